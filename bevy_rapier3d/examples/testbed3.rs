@@ -74,6 +74,7 @@ fn main() {
             RapierDebugRenderPlugin::default(),
             WorldInspectorPlugin::new(),
             RapierPickingPlugin,
+            RapierDragPlugin,
         ))
         .register_type::<Examples>()
         .register_type::<ExamplesRes>()
@@ -1,14 +1,21 @@
+#[cfg(doc)]
+use crate::geometry::ColliderShape;
 use crate::geometry::{Collider, CollisionGroups, ShapeCastHit};
 use crate::math::{Real, Rot, Vect};
 use bevy::prelude::*;
 
 use crate::plugin::context::RapierContextColliders;
+use crate::reflect::{
+    CharacterLengthWrapper, OptionCharacterAutostepWrapper, OptionCharacterLengthWrapper,
+    QueryFilterFlagsWrapper,
+};
 pub use rapier::control::CharacterAutostep;
 pub use rapier::control::CharacterLength;
 use rapier::prelude::{ColliderSet, QueryFilterFlags};
 
 /// A collision between the character and its environment during its movement.
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Debug, PartialEq)]
 pub struct CharacterCollision {
     /// The entity hit by the character.
     pub entity: Entity,
@@ -107,12 +114,19 @@ impl Default for MoveShapeOptions {
 }
 
 /// A character controller for kinematic bodies and free-standing colliders.
-#[derive(Clone, Debug, Component)] // TODO: Reflect
+#[derive(Clone, Debug, Component, Reflect)]
+#[reflect(Component, Default, Debug)]
 pub struct KinematicCharacterController {
     /// The translations we desire the character to move by if it doesn’t meet any obstacle.
     pub translation: Option<Vect>,
     /// The shape, and its position, to be used instead of the shape of the collider attached to
     /// the same entity is this `KinematicCharacterController`.
+    ///
+    /// This field isn’t reflected since [`Collider`] isn’t reflectable: it is `None` when the
+    /// controller is loaded from a scene or cloned through reflection. To save a controller with a
+    /// custom shape, save its description, for example as a [`ColliderShape`], and set this field
+    /// once the controller is loaded.
+    #[reflect(ignore)]
     pub custom_shape: Option<(Collider, Vect, Rot)>,
     /// The mass to be used for impulse of dynamic bodies. This replaces the mass of the rigid-body
     /// potentially associated to the collider attached to the same entity as this
@@ -126,10 +140,12 @@ pub struct KinematicCharacterController {
     ///
     /// This value should not be too large to avoid visual artifacts, but shouldn’t be too small
     /// (must not be zero) to improve numerical stability of the character controller.
+    #[reflect(remote = CharacterLengthWrapper)]
     pub offset: CharacterLength,
     /// Should the character try to slide against the floor if it hits it?
     pub slide: bool,
     /// Should the character automatically step over small obstacles?
+    #[reflect(remote = OptionCharacterAutostepWrapper)]
     pub autostep: Option<CharacterAutostep>,
     /// The maximum angle (radians) between the floor’s normal and the `up` vector that the
     /// character is able to climb.
@@ -141,9 +157,11 @@ pub struct KinematicCharacterController {
     pub apply_impulse_to_dynamic_bodies: bool,
     /// Should the character be automatically snapped to the ground if the distance between
     /// the ground and its feet are smaller than the specified threshold?
+    #[reflect(remote = OptionCharacterLengthWrapper)]
     pub snap_to_ground: Option<CharacterLength>,
     /// Flags for filtering-out some categories of entities from the environment seen by the
     /// character controller.
+    #[reflect(remote = QueryFilterFlagsWrapper)]
     pub filter_flags: QueryFilterFlags,
     /// Groups for filtering-out some colliders from the environment seen by the character
     /// controller.
@@ -207,7 +225,8 @@ impl Default for KinematicCharacterController {
 /// This component is automatically added after the first execution of a character control
 /// based on the `KinematicCharacterController` component with its
 /// `KinematicCharacterController::translation` set to a value other than `None`.
-#[derive(Clone, PartialEq, Debug, Default, Component)]
+#[derive(Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
 pub struct KinematicCharacterControllerOutput {
     /// Indicates whether the shape is grounded after its kinematic movement.
     pub grounded: bool,
@@ -230,3 +249,33 @@ pub struct MoveShapeOutput {
     /// Indicates whether the shape is sliding down a slope after its kinematic movement.
     pub is_sliding_down_slope: bool,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reflected_character_controller_roundtrip() {
+        let controller = KinematicCharacterController {
+            offset: CharacterLength::Absolute(0.1),
+            autostep: Some(CharacterAutostep {
+                max_height: CharacterLength::Absolute(0.5),
+                min_width: CharacterLength::Relative(0.2),
+                include_dynamic_bodies: true,
+            }),
+            snap_to_ground: None,
+            filter_flags: QueryFilterFlags::EXCLUDE_FIXED,
+            custom_shape: Some((Collider::ball(0.5), Vect::ZERO, Rot::default())),
+            ..default()
+        };
+
+        let loaded =
+            KinematicCharacterController::from_reflect(controller.as_partial_reflect()).unwrap();
+        assert_eq!(loaded.offset, controller.offset);
+        assert_eq!(loaded.autostep, controller.autostep);
+        assert_eq!(loaded.snap_to_ground, controller.snap_to_ground);
+        assert_eq!(loaded.filter_flags, controller.filter_flags);
+        // The custom shape isn't reflected.
+        assert!(loaded.custom_shape.is_none());
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Write as _;
+use std::io;
+
+use bevy::prelude::*;
+use rapier::math::{Point, Real};
+use rapier::pipeline::{DebugRenderBackend, DebugRenderObject, DebugRenderPipeline};
+
+use crate::plugin::context::systemparams::RapierContext;
+
+/// A [`DebugRenderBackend`] recording the lines rendered by a [`DebugRenderPipeline`], without
+/// requiring gizmos nor a renderer.
+///
+/// This is typically used to export a rapier context with [`DebugLinesRecorder::to_svg`], for bug
+/// reports or regression tests running in a GPU-less environment:
+///
+/// ```ignore
+/// fn export_lines(context: ReadRapierContext) {
+///     let mut pipeline = DebugRenderPipeline::default();
+///     let mut recorder = DebugLinesRecorder::default();
+///     recorder.record(&mut pipeline, &context.single().unwrap());
+///     std::fs::write("scene.svg", recorder.to_svg(&SvgOptions::default())).unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DebugLinesRecorder {
+    /// The recorded lines, as their start point, end point and color.
+    pub lines: Vec<(Vec3, Vec3, Color)>,
+}
+
+impl DebugRenderBackend for DebugLinesRecorder {
+    #[cfg(feature = "dim2")]
+    fn draw_line(
+        &mut self,
+        _object: DebugRenderObject,
+        a: Point<Real>,
+        b: Point<Real>,
+        color: [f32; 4],
+    ) {
+        self.lines.push((
+            Vec3::new(a.x, a.y, 0.0),
+            Vec3::new(b.x, b.y, 0.0),
+            Color::hsla(color[0], color[1], color[2], color[3]),
+        ));
+    }
+
+    #[cfg(feature = "dim3")]
+    fn draw_line(
+        &mut self,
+        _object: DebugRenderObject,
+        a: Point<Real>,
+        b: Point<Real>,
+        color: [f32; 4],
+    ) {
+        self.lines.push((
+            Vec3::new(a.x, a.y, a.z),
+            Vec3::new(b.x, b.y, b.z),
+            Color::hsla(color[0], color[1], color[2], color[3]),
+        ));
+    }
+}
+
+/// The options of the SVG exported by [`DebugLinesRecorder::to_svg`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    /// The rotation of the orthographic view the lines are projected on.
+    ///
+    /// The view is looking towards its local `-Z` axis, with its local `Y` axis pointing up, as
+    /// bevy cameras do. The identity shows the `XY` plane, as in 2D. In 3D, the rotation of a
+    /// camera [`Transform`] can be used, e.g. `Transform::default().looking_to(Vec3::NEG_Y, Vec3::Z).rotation`
+    /// for a top-down view.
+    pub view_rotation: Quat,
+    /// The width of the SVG image, in pixels. Its height is computed from the bounds of the lines.
+    pub width: f32,
+    /// The margin added around the bounds of the lines, in pixels.
+    pub margin: f32,
+    /// The width of the lines, in pixels.
+    pub stroke_width: f32,
+    /// The background color of the image, transparent if `None`.
+    pub background: Option<Color>,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            view_rotation: Quat::IDENTITY,
+            width: 800.0,
+            margin: 10.0,
+            stroke_width: 1.0,
+            background: Some(Color::WHITE),
+        }
+    }
+}
+
+impl DebugLinesRecorder {
+    /// Records the lines rendered by the given pipeline for the given rapier context.
+    pub fn record(&mut self, pipeline: &mut DebugRenderPipeline, context: &RapierContext) {
+        pipeline.render(
+            self,
+            &context.rigidbody_set.bodies,
+            &context.colliders.colliders,
+            &context.joints.impulse_joints,
+            &context.joints.multibody_joints,
+            &context.simulation.narrow_phase,
+        );
+    }
+
+    /// Removes all the recorded lines.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Writes the recorded lines as an SVG image, projected on the view described by `options`.
+    ///
+    /// The output only depends on the recorded lines and the options, so it can be compared to
+    /// a previously exported image in regression tests.
+    pub fn write_svg(&self, writer: &mut impl io::Write, options: &SvgOptions) -> io::Result<()> {
+        writer.write_all(self.to_svg(options).as_bytes())
+    }
+
+    /// The recorded lines as an SVG image, projected on the view described by `options`.
+    ///
+    /// See [`Self::write_svg`] for more details.
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let view = options.view_rotation.inverse();
+        // Project the lines on the view, with the Y axis pointing down as in SVG.
+        let project = |point: Vec3| {
+            let point = view * point;
+            Vec2::new(point.x, -point.y)
+        };
+        let lines: Vec<_> = self
+            .lines
+            .iter()
+            .map(|(a, b, color)| (project(*a), project(*b), *color))
+            .collect();
+
+        let (min, max) = lines
+            .iter()
+            .flat_map(|(a, b, _)| [*a, *b])
+            .fold(None, |bounds: Option<(Vec2, Vec2)>, point| {
+                Some(bounds.map_or((point, point), |(min, max)| {
+                    (min.min(point), max.max(point))
+                }))
+            })
+            .unwrap_or((Vec2::ZERO, Vec2::ZERO));
+        let inner_width = (options.width - 2.0 * options.margin).max(1.0);
+        let extents = (max - min).max(Vec2::splat(Real::EPSILON));
+        let scale = inner_width / extents.x.max(extents.y);
+        let height = extents.y * scale + 2.0 * options.margin;
+        let to_image = |point: Vec2| (point - min) * scale + options.margin;
+
+        let mut svg = String::new();
+        // Writing to a `String` never fails.
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.3} {:.3}">"#,
+            options.width, height, options.width, height
+        );
+        if let Some(background) = options.background {
+            let (fill, opacity) = svg_color(background);
+            let _ = writeln!(
+                svg,
+                r#"<rect width="100%" height="100%" fill="{fill}" fill-opacity="{opacity:.3}"/>"#
+            );
+        }
+        for (a, b, color) in lines {
+            let (a, b) = (to_image(a), to_image(b));
+            let (stroke, opacity) = svg_color(color);
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{:.3}" y1="{:.3}" x2="{:.3}" y2="{:.3}" stroke="{stroke}" stroke-opacity="{opacity:.3}" stroke-width="{:.3}"/>"#,
+                a.x, a.y, b.x, b.y, options.stroke_width
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// The opaque hexadecimal sRGB color and the opacity of the given color.
+fn svg_color(color: Color) -> (String, f32) {
+    let color = Srgba::from(color);
+    (color.with_alpha(1.0).to_hex(), color.alpha)
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::time::TimePlugin;
+
+    use rapier::pipeline::DebugRenderMode;
+
+    use super::*;
+    use crate::plugin::context::systemparams::ReadRapierContext;
+    use crate::prelude::*;
+
+    #[test]
+    fn debug_lines_are_recorded_and_exported() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.finish();
+        #[cfg(feature = "dim2")]
+        let collider = Collider::cuboid(1.0, 0.5);
+        #[cfg(feature = "dim3")]
+        let collider = Collider::cuboid(1.0, 0.5, 0.5);
+        app.world_mut().spawn((
+            Transform::from_xyz(1.0, 2.0, 0.0),
+            RigidBody::Fixed,
+            collider,
+        ));
+        app.update();
+
+        let recorder = app
+            .world_mut()
+            .run_system_once(|context: ReadRapierContext| {
+                let mut recorder = DebugLinesRecorder::default();
+                recorder.record(
+                    &mut DebugRenderPipeline::new(
+                        Default::default(),
+                        DebugRenderMode::COLLIDER_SHAPES,
+                    ),
+                    &context.single().unwrap(),
+                );
+                recorder
+            })
+            .unwrap();
+        assert!(!recorder.lines.is_empty());
+        for (a, b, _) in &recorder.lines {
+            for point in [a, b] {
+                assert!((point.x - 1.0).abs() <= 1.0 + 1.0e-5);
+                assert!((point.y - 2.0).abs() <= 0.5 + 1.0e-5);
+            }
+        }
+
+        let options = SvgOptions {
+            width: 220.0,
+            ..Default::default()
+        };
+        let svg = recorder.to_svg(&options);
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="220" height="120""#)
+        );
+        assert_eq!(svg.matches("<line ").count(), recorder.lines.len());
+        assert_eq!(
+            svg,
+            recorder.to_svg(&options),
+            "The export should be deterministic"
+        );
+
+        let mut bytes = Vec::new();
+        recorder.write_svg(&mut bytes, &options).unwrap();
+        assert_eq!(bytes, svg.as_bytes());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::dynamics::{FixedJoint, PrismaticJoint, RevoluteJoint, RopeJoint};
 use crate::math::{Real, Rot, Vect};
+use bevy::prelude::{Reflect, ReflectDefault};
 use rapier::dynamics::{
     GenericJoint as RapierGenericJoint, JointAxesMask, JointAxis, JointLimits, JointMotor,
     MotorModel,
@@ -7,10 +8,18 @@ use rapier::dynamics::{
 
 #[cfg(feature = "dim3")]
 use crate::dynamics::SphericalJoint;
+#[cfg(feature = "serde-serialize")]
+use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
 
 /// The description of any joint.
+///
+/// This type is reflected as an opaque value: it can only be serialized through reflection
+/// (e.g. in a `DynamicScene`) with the `serde-serialize` feature enabled.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Reflect)]
+#[reflect(opaque)]
+#[reflect(Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde-serialize", reflect(Serialize, Deserialize))]
 #[repr(transparent)]
 pub struct GenericJoint {
     /// The raw Rapier description of the joint.
@@ -1,3 +1,4 @@
+use bevy::ecs::{entity::MapEntities, reflect::ReflectMapEntities};
 use bevy::prelude::*;
 use rapier::dynamics::{ImpulseJointHandle, MultibodyJointHandle};
 
@@ -9,7 +10,8 @@ use super::{FixedJoint, GenericJoint, PrismaticJoint, RevoluteJoint, RopeJoint,
 use super::SphericalJoint;
 
 /// Wrapper enum over a specific joint.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
 pub enum TypedJoint {
     /// See [`FixedJoint`]
     FixedJoint(FixedJoint),
@@ -77,9 +79,13 @@ pub struct RapierMultibodyJointHandle(pub MultibodyJointHandle);
 /// joints can be added in the children of the entity containing that
 /// rigid-body (this is similar to the technique used to attach multiple
 /// colliders to the same rigid-body).
-#[derive(Copy, Clone, Debug, PartialEq, Component)]
+///
+/// The `parent` entity is remapped when this component is loaded from a scene.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect, MapEntities)]
+#[reflect(Component, MapEntities, Debug, PartialEq)]
 pub struct ImpulseJoint {
     /// The entity containing the rigid-body used as the first endpoint of this joint.
+    #[entities]
     pub parent: Entity,
     /// The joint’s description.
     pub data: TypedJoint,
@@ -104,9 +110,13 @@ impl ImpulseJoint {
 /// Note that a set of multibody joints cannot form closed loops (for example a necklace).
 /// If a closed loop is detected, the last joint that closes the loop is ignored, and an
 /// error is printed to `stderr` (using `log::error!`).
-#[derive(Copy, Clone, Debug, PartialEq, Component)]
+///
+/// The `parent` entity is remapped when this component is loaded from a scene.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect, MapEntities)]
+#[reflect(Component, MapEntities, Debug, PartialEq)]
 pub struct MultibodyJoint {
     /// The entity containing the rigid-body used as the first endpoint of this joint.
+    #[entities]
     pub parent: Entity,
     /// The joint’s description.
     pub data: TypedJoint,
@@ -119,3 +129,39 @@ impl MultibodyJoint {
         Self { parent, data }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::entity::EntityHashMap;
+    use bevy::reflect::TypeRegistry;
+
+    use super::*;
+    use crate::dynamics::RopeJointBuilder;
+    use crate::math::Vect;
+
+    #[test]
+    fn reflected_joint_parent_is_mapped() {
+        let mut world = World::new();
+        let saved_parent = world.spawn_empty().id();
+        let loaded_parent = world.spawn_empty().id();
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<ImpulseJoint>();
+        let joint = ImpulseJoint::new(
+            saved_parent,
+            RopeJointBuilder::new(2.0).local_anchor1(Vect::ONE),
+        );
+
+        let mut loaded = ImpulseJoint::from_reflect(joint.as_partial_reflect()).unwrap();
+        assert_eq!(loaded, joint);
+
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(saved_parent, loaded_parent);
+        registry
+            .get_type_data::<ReflectMapEntities>(std::any::TypeId::of::<ImpulseJoint>())
+            .unwrap()
+            .map_entities(&mut loaded, &mut entity_map);
+        assert_eq!(loaded.parent, loaded_parent);
+        assert_eq!(loaded.data, joint.data);
+    }
+}
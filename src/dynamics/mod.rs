@@ -60,3 +60,14 @@ impl From<CoefficientCombineRule> for RapierCoefficientCombineRule {
         }
     }
 }
+
+impl From<RapierCoefficientCombineRule> for CoefficientCombineRule {
+    fn from(combine_rule: RapierCoefficientCombineRule) -> CoefficientCombineRule {
+        match combine_rule {
+            RapierCoefficientCombineRule::Average => CoefficientCombineRule::Average,
+            RapierCoefficientCombineRule::Min => CoefficientCombineRule::Min,
+            RapierCoefficientCombineRule::Multiply => CoefficientCombineRule::Multiply,
+            RapierCoefficientCombineRule::Max => CoefficientCombineRule::Max,
+        }
+    }
+}
@@ -154,6 +154,7 @@ impl Velocity {
 /// Mass-properties of a [`RigidBody`], added to the contributions of its attached colliders.
 ///
 /// This only affects entities with a [`RigidBody`] component.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub enum AdditionalMassProperties {
@@ -215,6 +216,7 @@ impl From<Entity> for MassModifiedEvent {
 ///
 /// This cannot be used as a component. Use the components `ReadMassProperties` to read a [`RigidBody`]’s
 /// mass-properties or `AdditionalMassProperties` to set its additional mass-properties.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
 #[reflect(Default, PartialEq)]
 pub struct MassProperties {
@@ -269,6 +271,7 @@ impl MassProperties {
     }
 }
 
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Default, Debug, Component, Reflect, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[reflect(Component, Default, PartialEq)]
 /// Flags affecting the behavior of the constraints solver for a given contact manifold.
@@ -304,6 +307,7 @@ impl From<LockedAxes> for RapierLockedAxes {
 /// Constant external forces applied continuously to a [`RigidBody`].
 ///
 /// This force is applied at each timestep.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct ExternalForce {
@@ -376,6 +380,7 @@ impl SubAssign for ExternalForce {
 ///
 /// The impulse is only applied once, and whenever it it modified (based
 /// on Bevy’s change detection).
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct ExternalImpulse {
@@ -451,6 +456,7 @@ impl SubAssign for ExternalImpulse {
 
 /// Gravity is multiplied by this scaling factor before it's
 /// applied to this [`RigidBody`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct GravityScale(pub f32);
@@ -462,6 +468,7 @@ impl Default for GravityScale {
 }
 
 /// Information used for Continuous-Collision-Detection.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Ccd {
@@ -502,6 +509,7 @@ pub struct SoftCcd {
 }
 
 /// The dominance groups of a [`RigidBody`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Dominance {
@@ -521,6 +529,7 @@ impl Dominance {
 ///
 /// This controls whether a body is sleeping or not.
 /// If the threshold is negative, the body never sleeps.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Sleeping {
@@ -557,6 +566,7 @@ impl Default for Sleeping {
 }
 
 /// Damping factors to gradually slow down a [`RigidBody`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Damping {
@@ -579,11 +589,16 @@ impl Default for Damping {
 /// If the `TimestepMode::Interpolated` mode is set and this component is present,
 /// the associated [`RigidBody`] will have its position automatically interpolated
 /// between the last two [`RigidBody`] positions set by the physics engine.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Component)]
+///
+/// The interpolation points are computed at each simulation step, so they aren't reflected.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
 pub struct TransformInterpolation {
     /// The starting point of the interpolation.
+    #[reflect(ignore)]
     pub start: Option<Isometry<f32>>,
     /// The end point of the interpolation.
+    #[reflect(ignore)]
     pub end: Option<Isometry<f32>>,
 }
 
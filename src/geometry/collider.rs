@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use {
     crate::geometry::{TriMeshFlags, VHACDParameters},
     bevy::platform::collections::HashMap,
@@ -13,22 +13,75 @@ use rapier::geometry::Shape;
 use rapier::prelude::{ColliderHandle, InteractionGroups, SharedShape};
 
 use crate::dynamics::{CoefficientCombineRule, MassProperties};
-use crate::math::Vect;
+use crate::math::{Real, Vect};
 
 #[cfg(doc)]
-use rapier::{dynamics::RigidBody, geometry::ContactForceEvent};
+use {
+    crate::geometry::ColliderShape,
+    crate::pipeline::ImpactEvent,
+    rapier::{dynamics::RigidBody, geometry::ContactForceEvent},
+};
 
 /// The Rapier handle of a collider that was inserted to the physics scene.
 #[derive(Copy, Clone, Debug, Component)]
 pub struct RapierColliderHandle(pub ColliderHandle);
 
 /// A component which will be replaced by the specified collider type after the referenced mesh become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+///
+/// The mesh is referenced by the [`Mesh3d`] component in 3D, and by the [`Mesh2d`] component in 2D.
+///
+/// The collider is computed on the [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool) and
+/// shared with the entities using the same mesh and shape, see [`AsyncColliderStatus`] and
+/// [`MeshColliderCache`](crate::geometry::MeshColliderCache). Modifying this component while its
+/// collider is being computed restarts the computation.
+#[cfg(feature = "async-collider")]
 #[derive(Component, Debug, Clone, Default)]
 pub struct AsyncCollider(pub ComputedColliderShape);
 
+/// A component keeping the [`Collider`] of an entity up to date with its mesh, computed with the
+/// specified shape.
+///
+/// Unlike [`AsyncCollider`], this component isn't removed once the collider is created: the
+/// collider is recomputed whenever the mesh asset is modified (e.g. hot-reloaded or regenerated
+/// procedurally), whenever the mesh handle is replaced, or whenever this component is modified.
+/// The collider is computed like the one of an [`AsyncCollider`], and the previous collider is
+/// kept until the new one is inserted.
+#[cfg(feature = "async-collider")]
+#[derive(Component, Debug, Clone, Default)]
+pub struct ColliderFromMesh(pub ComputedColliderShape);
+
+/// The status of a collider computed from the mesh of an [`AsyncCollider`], a [`ColliderFromMesh`],
+/// or from a mesh of an [`AsyncSceneCollider`].
+///
+/// This component is inserted on the mesh entity when the computation starts, and removed once
+/// the [`Collider`] is inserted.
+#[cfg(feature = "async-collider")]
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub enum AsyncColliderStatus {
+    /// The collider is being computed.
+    Pending,
+    /// The collider couldn't be computed from the mesh.
+    ///
+    /// The [`AsyncCollider`] is kept on the entity, so that modifying it retries the computation.
+    Failed,
+}
+
+/// The collider of an entity whose [`AsyncColliderStatus`] is pending, being computed in the
+/// [`MeshColliderCache`](crate::geometry::MeshColliderCache).
+#[cfg(feature = "async-collider")]
+#[derive(Component, Debug, Clone)]
+pub struct AsyncColliderTask {
+    /// The mesh the collider is computed from.
+    pub mesh: AssetId<Mesh>,
+    /// The shape computed from the mesh.
+    pub shape: ComputedColliderShape,
+}
+
 /// A component which will be replaced the specified collider types on children with meshes after the referenced scene become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+///
+/// The meshes are referenced by [`Mesh3d`] components in 3D, and by [`Mesh2d`] components in 2D.
+#[cfg(feature = "async-collider")]
 #[derive(Component, Debug, Clone)]
 pub struct AsyncSceneCollider {
     /// Collider type for each scene mesh not included in [`Self::named_shapes`]. If [`None`], then all
@@ -37,31 +90,89 @@ pub struct AsyncSceneCollider {
     /// Shape types for meshes by name. If shape is [`None`], then it will be skipped for
     /// processing.
     pub named_shapes: HashMap<String, Option<ComputedColliderShape>>,
+    /// Whether the shape and physics properties of each mesh are read from its glTF extras and
+    /// the ones of its node, see [`GltfColliderExtras`](crate::geometry::GltfColliderExtras).
+    ///
+    /// A shape declared in the extras takes precedence over [`Self::named_shapes`] and
+    /// [`Self::shape`].
+    #[cfg(all(feature = "dim3", feature = "gltf"))]
+    pub use_gltf_extras: bool,
+    /// The glTF asset the scene was loaded from, to import the rigid-bodies, colliders and joints
+    /// described by its `KHR_physics_rigid_bodies`, `KHR_implicit_shapes`, `OMI_physics_body`,
+    /// `OMI_physics_shape` and `OMI_physics_joint` extensions.
+    ///
+    /// The asset must be loaded with [`GltfLoaderSettings::include_source`](bevy::gltf::GltfLoaderSettings::include_source)
+    /// enabled, and the nodes with physics extensions must have unique names. Set [`Self::shape`]
+    /// to `None` to only create the colliders described by the extensions. If the asset fails to
+    /// load, a warning is logged and the scene colliders are created without it.
+    #[cfg(all(feature = "dim3", feature = "gltf"))]
+    pub gltf_physics: Option<Handle<bevy::gltf::Gltf>>,
 }
 
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 impl Default for AsyncSceneCollider {
     fn default() -> Self {
         Self {
             shape: Some(Default::default()),
             named_shapes: Default::default(),
+            #[cfg(all(feature = "dim3", feature = "gltf"))]
+            use_gltf_extras: false,
+            #[cfg(all(feature = "dim3", feature = "gltf"))]
+            gltf_physics: None,
         }
     }
 }
 
 /// Shape type based on a Bevy mesh asset.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 #[derive(Debug, Clone)]
 pub enum ComputedColliderShape {
     /// Triangle-mesh.
     TriMesh(TriMeshFlags),
     /// Convex hull.
+    ///
+    /// In 2D, this is the convex polygon enclosing the mesh vertices.
     ConvexHull,
     /// Convex decomposition.
+    ///
+    /// In 2D, this is the decomposition of the outline of the mesh, made of the edges which
+    /// belong to a single triangle.
     ConvexDecomposition(VHACDParameters),
-}
-
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+    /// Polyline made of the outline of the mesh, i.e. the edges which belong to a single
+    /// triangle.
+    #[cfg(feature = "dim2")]
+    Polyline,
+    /// Axis-aligned bounding box of the mesh vertices.
+    Aabb {
+        /// The distance the box is enlarged by on each side.
+        margin: Real,
+    },
+    /// Oriented bounding box of the mesh vertices, aligned with their principal axes.
+    ///
+    /// The box isn't guaranteed to be the smallest one enclosing the vertices.
+    Obb {
+        /// The distance the box is enlarged by on each side.
+        margin: Real,
+    },
+    /// Bounding sphere (circle in 2D) of the mesh vertices.
+    BoundingSphere {
+        /// The distance added to the radius of the sphere.
+        margin: Real,
+    },
+    /// Capsule enclosing the mesh vertices, aligned with their principal axis.
+    Capsule {
+        /// The distance added to the radius of the capsule.
+        margin: Real,
+    },
+    /// Cylinder enclosing the mesh vertices, aligned with their principal axis.
+    #[cfg(feature = "dim3")]
+    Cylinder {
+        /// The distance the cylinder is enlarged by on each side.
+        margin: Real,
+    },
+}
+
+#[cfg(feature = "async-collider")]
 impl Default for ComputedColliderShape {
     fn default() -> Self {
         Self::TriMesh(TriMeshFlags::MERGE_DUPLICATE_VERTICES)
@@ -84,6 +195,8 @@ impl Default for ComputedColliderShape {
 /// - [`CollidingEntities`]
 /// - [`ColliderScale`]
 /// - [`ColliderDisabled`]
+///
+/// This component isn't reflectable: use [`ColliderShape`] to describe colliders in scenes.
 #[derive(Component, Clone)] // TODO: Reflect
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct Collider {
@@ -131,6 +244,7 @@ pub enum ColliderScale {
 pub struct Sensor;
 
 /// Custom mass-properties of a [`Collider`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub enum ColliderMassProperties {
@@ -149,6 +263,7 @@ impl Default for ColliderMassProperties {
 }
 
 /// The friction affecting a [`Collider`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Friction {
@@ -191,6 +306,7 @@ impl Friction {
 }
 
 /// The restitution affecting a [`Collider`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, Default, PartialEq)]
 pub struct Restitution {
@@ -505,6 +621,15 @@ impl Default for ContactForceEventThreshold {
     }
 }
 
+/// The minimum relative normal speed of the impacts of a collider emitting an [`ImpactEvent`].
+///
+/// When both colliders involved in an impact have a threshold, the smallest one is used. The
+/// impacts between colliders without threshold don't emit any [`ImpactEvent`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct ImpactSpeedThreshold(pub Real);
+
 /// Sets the contact skin of the collider.
 ///
 /// The contact skin acts as if the collider was enlarged with a skin of width `skin_thickness`
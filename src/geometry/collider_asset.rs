@@ -0,0 +1,259 @@
+use std::fmt;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use crate::geometry::{
+    Collider, ColliderMassProperties, ColliderShape, CollisionGroups, Friction, Restitution,
+};
+
+/// A collider shape and some of its properties, shared by every entity referencing it through
+/// an [`AsyncColliderAsset`].
+///
+/// Since the shape of a [`Collider`] is reference-counted, all the colliders created from the
+/// same asset share the same (possibly expensive to compute) triangle mesh or convex
+/// decomposition.
+///
+/// Collider assets are loaded by the [`ColliderAssetLoader`] from `.collider.ron` or
+/// `.collider.bin` files containing a [`ColliderAssetDescriptor`].
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct ColliderAsset {
+    /// The collider inserted on the entities referencing this asset.
+    pub collider: Collider,
+    /// The friction inserted on the entities referencing this asset, if any.
+    pub friction: Option<Friction>,
+    /// The restitution inserted on the entities referencing this asset, if any.
+    pub restitution: Option<Restitution>,
+    /// The collision groups inserted on the entities referencing this asset, if any.
+    pub collision_groups: Option<CollisionGroups>,
+    /// The mass properties inserted on the entities referencing this asset, if any.
+    pub mass_properties: Option<ColliderMassProperties>,
+}
+
+impl From<Collider> for ColliderAsset {
+    fn from(collider: Collider) -> Self {
+        Self {
+            collider,
+            friction: None,
+            restitution: None,
+            collision_groups: None,
+            mass_properties: None,
+        }
+    }
+}
+
+impl ColliderAsset {
+    /// Inserts the collider and the properties of this asset on the given entity.
+    ///
+    /// The components of the properties set to `None` are removed from the entity, so that the
+    /// properties removed from a hot-reloaded asset don't keep their previous value.
+    pub fn insert_into(&self, entity: &mut EntityCommands) {
+        entity.insert(self.collider.clone());
+        match self.friction {
+            Some(friction) => entity.insert(friction),
+            None => entity.remove::<Friction>(),
+        };
+        match self.restitution {
+            Some(restitution) => entity.insert(restitution),
+            None => entity.remove::<Restitution>(),
+        };
+        match self.collision_groups {
+            Some(collision_groups) => entity.insert(collision_groups),
+            None => entity.remove::<CollisionGroups>(),
+        };
+        match self.mass_properties {
+            Some(mass_properties) => entity.insert(mass_properties),
+            None => entity.remove::<ColliderMassProperties>(),
+        };
+    }
+}
+
+/// A component which will insert the [`Collider`] and properties of a [`ColliderAsset`] once it
+/// is loaded.
+///
+/// Unlike [`AsyncCollider`](crate::geometry::AsyncCollider), this component isn't removed once
+/// the collider is created: the collider and its properties are updated whenever the asset is
+/// modified, e.g. when it is hot-reloaded.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct AsyncColliderAsset(pub Handle<ColliderAsset>);
+
+/// The shape of a [`ColliderAssetDescriptor`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ColliderAssetShape {
+    /// A description of the shape, built when the asset is loaded.
+    Shape(ColliderShape),
+    /// A shape computed beforehand, for example a convex decomposition saved to avoid computing
+    /// it at runtime.
+    Collider(Collider),
+}
+
+/// The serialized form of a [`ColliderAsset`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ColliderAssetDescriptor {
+    /// The shape of the collider.
+    pub shape: ColliderAssetShape,
+    /// The friction of the collider, if any.
+    #[serde(default)]
+    pub friction: Option<Friction>,
+    /// The restitution of the collider, if any.
+    #[serde(default)]
+    pub restitution: Option<Restitution>,
+    /// The collision groups of the collider, if any.
+    #[serde(default)]
+    pub collision_groups: Option<CollisionGroups>,
+    /// The mass properties of the collider, if any.
+    #[serde(default)]
+    pub mass_properties: Option<ColliderMassProperties>,
+}
+
+impl ColliderAssetDescriptor {
+    /// Builds the collider asset described by `self`.
+    ///
+    /// Returns `None` if the described shape is invalid, see [`ColliderShape::to_collider`].
+    pub fn to_asset(&self) -> Option<ColliderAsset> {
+        let collider = match &self.shape {
+            ColliderAssetShape::Shape(shape) => shape.to_collider()?,
+            ColliderAssetShape::Collider(collider) => collider.clone(),
+        };
+        Some(ColliderAsset {
+            collider,
+            friction: self.friction,
+            restitution: self.restitution,
+            collision_groups: self.collision_groups,
+            mass_properties: self.mass_properties,
+        })
+    }
+}
+
+impl From<&ColliderAsset> for ColliderAssetDescriptor {
+    /// Describes the given asset with its computed shape, to save it in a binary file for example.
+    fn from(asset: &ColliderAsset) -> Self {
+        Self {
+            shape: ColliderAssetShape::Collider(asset.collider.clone()),
+            friction: asset.friction,
+            restitution: asset.restitution,
+            collision_groups: asset.collision_groups,
+            mass_properties: asset.mass_properties,
+        }
+    }
+}
+
+/// Loads [`ColliderAsset`]s from [`ColliderAssetDescriptor`]s serialized in `.collider.ron`
+/// files with [`ron`], or in `.collider.bin` files with [`bincode`].
+#[derive(Default)]
+pub struct ColliderAssetLoader;
+
+/// An error that occurred while loading a [`ColliderAsset`].
+#[derive(Debug)]
+pub enum ColliderAssetLoaderError {
+    /// The file couldn't be read.
+    Io(std::io::Error),
+    /// The `.collider.ron` file couldn't be parsed.
+    Ron(ron::error::SpannedError),
+    /// The `.collider.bin` file couldn't be parsed.
+    Bincode(bincode::Error),
+    /// The file was parsed but its shape is invalid.
+    InvalidShape(ColliderAssetShape),
+}
+
+impl fmt::Display for ColliderAssetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read the collider asset: {err}"),
+            Self::Ron(err) => write!(f, "could not parse the collider asset: {err}"),
+            Self::Bincode(err) => write!(f, "could not parse the collider asset: {err}"),
+            Self::InvalidShape(shape) => write!(f, "could not build a collider from {shape:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ColliderAssetLoaderError {}
+
+impl From<std::io::Error> for ColliderAssetLoaderError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl ColliderAssetLoader {
+    fn parse(bytes: &[u8], is_ron: bool) -> Result<ColliderAsset, ColliderAssetLoaderError> {
+        let descriptor: ColliderAssetDescriptor = if is_ron {
+            ron::de::from_bytes(bytes).map_err(ColliderAssetLoaderError::Ron)?
+        } else {
+            bincode::deserialize(bytes).map_err(ColliderAssetLoaderError::Bincode)?
+        };
+
+        descriptor
+            .to_asset()
+            .ok_or(ColliderAssetLoaderError::InvalidShape(descriptor.shape))
+    }
+}
+
+impl AssetLoader for ColliderAssetLoader {
+    type Asset = ColliderAsset;
+    type Settings = ();
+    type Error = ColliderAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_ron = load_context
+            .path()
+            .extension()
+            .is_some_and(|extension| extension == "ron");
+        Self::parse(&bytes, is_ron)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["collider.ron", "collider.bin"]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::Vect;
+
+    #[test]
+    fn collider_asset_formats() {
+        let ron = r#"(
+            shape: Shape(Ball(radius: 0.5)),
+            friction: Some((coefficient: 0.2, combine_rule: Average)),
+        )"#;
+        let asset = ColliderAssetLoader::parse(ron.as_bytes(), true).unwrap();
+        assert_eq!(asset.collider.as_ball().unwrap().radius(), 0.5);
+        assert_eq!(asset.friction.unwrap().coefficient, 0.2);
+        assert_eq!(asset.restitution, None);
+
+        let invalid = "(shape: Shape(ConvexHull(points: [])))";
+        assert!(matches!(
+            ColliderAssetLoader::parse(invalid.as_bytes(), true),
+            Err(ColliderAssetLoaderError::InvalidShape(_))
+        ));
+
+        let asset = ColliderAsset {
+            restitution: Some(Restitution::coefficient(0.7)),
+            ..ColliderAsset::from(
+                ColliderShape::Cuboid {
+                    half_extents: Vect::ONE,
+                }
+                .to_collider()
+                .unwrap(),
+            )
+        };
+        let bytes = bincode::serialize(&ColliderAssetDescriptor::from(&asset)).unwrap();
+        let loaded = ColliderAssetLoader::parse(&bytes, false).unwrap();
+        assert_eq!(
+            loaded.collider.as_cuboid().unwrap().half_extents(),
+            Vect::ONE
+        );
+        assert_eq!(loaded.restitution, asset.restitution);
+    }
+}
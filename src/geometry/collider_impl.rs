@@ -1,6 +1,6 @@
 #[cfg(feature = "dim2")]
 use na::DVector;
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use {
     bevy::prelude::*,
     bevy::render::mesh::{Indices, VertexAttributeValues},
@@ -12,7 +12,7 @@ use rapier::{
 };
 
 use super::{get_snapped_scale, shape_views::*};
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use crate::geometry::ComputedColliderShape;
 use crate::math::{Real, Rot, Vect};
 use crate::{
@@ -295,8 +295,10 @@ impl Collider {
 
     /// Initializes a collider with a Bevy Mesh.
     ///
+    /// In 2D, the `z` coordinate of the mesh vertices is ignored.
+    ///
     /// Returns `None` if the index buffer or vertex buffer of the mesh are in an incompatible format.
-    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    #[cfg(feature = "async-collider")]
     pub fn from_bevy_mesh(mesh: &Mesh, collider_shape: &ComputedColliderShape) -> Option<Self> {
         let (vtx, idx) = extract_mesh_vertices_indices(mesh)?;
 
@@ -309,9 +311,30 @@ impl Collider {
             ComputedColliderShape::ConvexHull => {
                 SharedShape::convex_hull(&vtx).map(|shape| shape.into())
             }
+            #[cfg(feature = "dim2")]
+            ComputedColliderShape::ConvexDecomposition(params) => {
+                let outline = mesh_outline(&idx);
+                Some(SharedShape::convex_decomposition_with_params(&vtx, &outline, params).into())
+            }
+            #[cfg(feature = "dim3")]
             ComputedColliderShape::ConvexDecomposition(params) => {
                 Some(SharedShape::convex_decomposition_with_params(&vtx, &idx, params).into())
             }
+            #[cfg(feature = "dim2")]
+            ComputedColliderShape::Polyline => {
+                let outline = mesh_outline(&idx);
+                Some(SharedShape::polyline(vtx, Some(outline)).into())
+            }
+            ComputedColliderShape::Aabb { .. }
+            | ComputedColliderShape::Obb { .. }
+            | ComputedColliderShape::BoundingSphere { .. }
+            | ComputedColliderShape::Capsule { .. } => {
+                fit_primitive(&vtx, collider_shape).map(Into::into)
+            }
+            #[cfg(feature = "dim3")]
+            ComputedColliderShape::Cylinder { .. } => {
+                fit_primitive(&vtx, collider_shape).map(Into::into)
+            }
         }
     }
 
@@ -873,25 +896,22 @@ impl Default for Collider {
     }
 }
 
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 #[allow(clippy::type_complexity)]
-fn extract_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<na::Point3<Real>>, Vec<[u32; 3]>)> {
+fn extract_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<Point<Real>>, Vec<[u32; 3]>)> {
     use rapier::na::point;
 
     let vertices = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?;
     let indices = mesh.indices()?;
 
+    #[cfg(feature = "dim2")]
+    let to_point = |v: &[f32]| point![v[0] as Real, v[1] as Real];
+    #[cfg(feature = "dim3")]
+    let to_point = |v: &[f32]| point![v[0] as Real, v[1] as Real, v[2] as Real];
+
     let vtx: Vec<_> = match vertices {
-        VertexAttributeValues::Float32(vtx) => Some(
-            vtx.chunks(3)
-                .map(|v| point![v[0] as Real, v[1] as Real, v[2] as Real])
-                .collect(),
-        ),
-        VertexAttributeValues::Float32x3(vtx) => Some(
-            vtx.iter()
-                .map(|v| point![v[0] as Real, v[1] as Real, v[2] as Real])
-                .collect(),
-        ),
+        VertexAttributeValues::Float32(vtx) => Some(vtx.chunks(3).map(to_point).collect()),
+        VertexAttributeValues::Float32x3(vtx) => Some(vtx.iter().map(|v| to_point(v)).collect()),
         _ => None,
     }?;
 
@@ -905,3 +925,259 @@ fn extract_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<na::Point3<Real>>,
 
     Some((vtx, idx))
 }
+
+/// The primitive shape of the given kind fitted to the given points, or `None` if there are no
+/// points.
+#[cfg(feature = "async-collider")]
+fn fit_primitive(points: &[Point<Real>], shape: &ComputedColliderShape) -> Option<SharedShape> {
+    use rapier::parry::{
+        bounding_volume::{details::point_cloud_bounding_sphere, Aabb},
+        shape::Cuboid,
+        utils::obb,
+    };
+
+    if points.is_empty() {
+        return None;
+    }
+
+    let shape = match *shape {
+        ComputedColliderShape::Aabb { margin } => {
+            let aabb = Aabb::from_points(points);
+            positioned(
+                SharedShape::new(Cuboid::new(aabb.half_extents().add_scalar(margin))),
+                aabb.center().coords.into(),
+            )
+        }
+        ComputedColliderShape::Obb { margin } => {
+            let (position, cuboid) = obb(points);
+            positioned(
+                SharedShape::new(Cuboid::new(cuboid.half_extents.add_scalar(margin))),
+                position,
+            )
+        }
+        ComputedColliderShape::BoundingSphere { margin } => {
+            let sphere = point_cloud_bounding_sphere(points);
+            positioned(
+                SharedShape::ball(sphere.radius() + margin),
+                sphere.center().coords.into(),
+            )
+        }
+        ComputedColliderShape::Capsule { margin } => {
+            let (center, axis, projections) = principal_axis_projections(points);
+            let radius = projections.iter().fold(0.0, |radius, (_, r)| r.max(radius));
+            // Find the segment whose caps contain the points beyond its ends.
+            let (mut a, mut b) = (Real::MAX, -Real::MAX);
+            for (t, r) in &projections {
+                let cap = (radius * radius - r * r).max(0.0).sqrt();
+                a = a.min(t + cap);
+                b = b.max(t - cap);
+            }
+            if a > b {
+                a = (a + b) / 2.0;
+                b = a;
+            }
+            SharedShape::capsule(center + axis * a, center + axis * b, radius + margin)
+        }
+        #[cfg(feature = "dim3")]
+        ComputedColliderShape::Cylinder { margin } => {
+            use rapier::{na::UnitQuaternion, prelude::Isometry};
+
+            let (center, axis, projections) = principal_axis_projections(points);
+            let (mut min, mut max, mut radius) = (Real::MAX, -Real::MAX, 0.0);
+            for (t, r) in projections {
+                min = min.min(t);
+                max = max.max(t);
+                radius = r.max(radius);
+            }
+            // The cylinder is symmetric, so its axis can be flipped if it is opposite to `y`.
+            let rotation = UnitQuaternion::rotation_between(&Vector::y(), &axis)
+                .unwrap_or_else(UnitQuaternion::identity);
+            positioned(
+                SharedShape::cylinder((max - min) / 2.0 + margin, radius + margin),
+                Isometry::from_parts((center + axis * ((min + max) / 2.0)).into(), rotation),
+            )
+        }
+        ComputedColliderShape::TriMesh(_)
+        | ComputedColliderShape::ConvexHull
+        | ComputedColliderShape::ConvexDecomposition(_) => return None,
+        #[cfg(feature = "dim2")]
+        ComputedColliderShape::Polyline => return None,
+    };
+    Some(shape)
+}
+
+/// Wraps the given shape into a compound shape if it isn't positioned at the origin.
+#[cfg(feature = "async-collider")]
+fn positioned(shape: SharedShape, position: rapier::prelude::Isometry<Real>) -> SharedShape {
+    if position == rapier::prelude::Isometry::identity() {
+        shape
+    } else {
+        SharedShape::compound(vec![(position, shape)])
+    }
+}
+
+/// The center and principal axis of the given points, and the coordinate of each point along
+/// the axis with its distance to the axis.
+///
+/// The center is the middle of the extents of the points along their principal axes, which
+/// unlike their average doesn't depend on how the vertices are distributed on the mesh.
+#[cfg(feature = "async-collider")]
+fn principal_axis_projections(
+    points: &[Point<Real>],
+) -> (Point<Real>, Vector<Real>, Vec<(Real, Real)>) {
+    let eigen = rapier::parry::utils::cov(points).symmetric_eigen();
+    let axes = eigen.eigenvectors;
+    let axis = axes.column(eigen.eigenvalues.imax()).into_owned();
+    let mut mins = Vector::repeat(Real::MAX);
+    let mut maxs = Vector::repeat(-Real::MAX);
+    for point in points {
+        let local = axes.tr_mul(&point.coords);
+        mins = mins.inf(&local);
+        maxs = maxs.sup(&local);
+    }
+    let center = Point::from(axes * (mins + maxs) / 2.0);
+    let projections = points
+        .iter()
+        .map(|point| {
+            let dpt = point - center;
+            let t = dpt.dot(&axis);
+            (t, (dpt - axis * t).norm())
+        })
+        .collect();
+    (center, axis, projections)
+}
+
+/// The edges of the given triangles which don't belong to any other triangle, with the
+/// orientation they have in their triangle.
+#[cfg(all(feature = "dim2", feature = "async-collider"))]
+fn mesh_outline(triangles: &[[u32; 3]]) -> Vec<[u32; 2]> {
+    use bevy::platform::collections::HashMap;
+
+    let mut edges: HashMap<[u32; 2], ([u32; 2], usize)> = HashMap::default();
+    for tri in triangles {
+        for edge in [[tri[0], tri[1]], [tri[1], tri[2]], [tri[2], tri[0]]] {
+            let key = [edge[0].min(edge[1]), edge[0].max(edge[1])];
+            edges.entry(key).or_insert((edge, 0)).1 += 1;
+        }
+    }
+
+    let mut outline: Vec<_> = edges
+        .into_values()
+        .filter(|(_, count)| *count == 1)
+        .map(|(edge, _)| edge)
+        .collect();
+    // Keep the result independent from the hash map iteration order.
+    outline.sort_unstable();
+    outline
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn primitives_are_fitted_to_meshes() {
+        use super::*;
+
+        let fit = |mesh: Mesh, shape| Collider::from_bevy_mesh(&mesh, &shape).unwrap();
+        let cuboid = Mesh::from(Cuboid::new(2.0, 1.0, 1.0)).translated_by(Vec3::X);
+
+        let aabb = fit(cuboid.clone(), ComputedColliderShape::Aabb { margin: 0.1 });
+        let aabb = aabb.as_compound().unwrap();
+        let (translation, _, shape) = aabb.shapes().next().unwrap();
+        let ColliderView::Cuboid(shape) = shape else {
+            panic!("The AABB should be a cuboid");
+        };
+        assert!(translation.abs_diff_eq(Vec3::X, 1.0e-5));
+        assert!(shape
+            .half_extents()
+            .abs_diff_eq(Vec3::new(1.1, 0.6, 0.6), 1.0e-5));
+
+        let sphere = fit(
+            cuboid,
+            ComputedColliderShape::BoundingSphere { margin: 0.0 },
+        );
+        let sphere = sphere.as_compound().unwrap();
+        let (translation, _, shape) = sphere.shapes().next().unwrap();
+        let ColliderView::Ball(shape) = shape else {
+            panic!("The bounding sphere should be a ball");
+        };
+        assert!(translation.abs_diff_eq(Vec3::X, 1.0e-5));
+        assert!((shape.radius() - 1.5_f32.sqrt()).abs() < 1.0e-5);
+
+        let capsule = fit(
+            Capsule3d::new(0.5, 1.0).into(),
+            ComputedColliderShape::Capsule { margin: 0.0 },
+        );
+        let capsule = capsule.as_capsule().unwrap();
+        assert!((capsule.radius() - 0.5).abs() < 1.0e-3);
+        assert!((capsule.half_height() - 0.5).abs() < 1.0e-3);
+
+        let cylinder = fit(
+            Cylinder::new(0.5, 1.0).into(),
+            ComputedColliderShape::Cylinder { margin: 0.0 },
+        );
+        let cylinder = cylinder.as_cylinder().unwrap();
+        assert!((cylinder.radius() - 0.5).abs() < 1.0e-3);
+        assert!((cylinder.half_height() - 0.5).abs() < 1.0e-3);
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim2", feature = "async-collider"))]
+    fn primitives_are_fitted_to_meshes_2d() {
+        use super::*;
+
+        let fit = |mesh: Mesh, shape| Collider::from_bevy_mesh(&mesh, &shape).unwrap();
+        let rectangle = Mesh::from(Rectangle::new(2.0, 1.0)).translated_by(Vec3::X);
+
+        let aabb = fit(
+            rectangle.clone(),
+            ComputedColliderShape::Aabb { margin: 0.1 },
+        );
+        let aabb = aabb.as_compound().unwrap();
+        let (translation, _, shape) = aabb.shapes().next().unwrap();
+        let ColliderView::Cuboid(shape) = shape else {
+            panic!("The AABB should be a cuboid");
+        };
+        assert!(translation.abs_diff_eq(Vect::X, 1.0e-5));
+        assert!(shape
+            .half_extents()
+            .abs_diff_eq(Vect::new(1.1, 0.6), 1.0e-5));
+
+        let obb = fit(
+            Mesh::from(Rectangle::new(2.0, 1.0))
+                .rotated_by(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            ComputedColliderShape::Obb { margin: 0.0 },
+        );
+        let half_extents = match obb.as_unscaled_typed_shape() {
+            ColliderView::Cuboid(shape) => shape.half_extents(),
+            ColliderView::Compound(compound) => match compound.shapes().next().unwrap() {
+                (_, _, ColliderView::Cuboid(shape)) => shape.half_extents(),
+                _ => panic!("The OBB should be a cuboid"),
+            },
+            _ => panic!("The OBB should be a cuboid"),
+        };
+        let (min, max) = (half_extents.min_element(), half_extents.max_element());
+        assert!((min - 0.5).abs() < 1.0e-3 && (max - 1.0).abs() < 1.0e-3);
+
+        let circle = fit(
+            rectangle,
+            ComputedColliderShape::BoundingSphere { margin: 0.0 },
+        );
+        let circle = circle.as_compound().unwrap();
+        let (translation, _, shape) = circle.shapes().next().unwrap();
+        let ColliderView::Ball(shape) = shape else {
+            panic!("The bounding circle should be a ball");
+        };
+        assert!(translation.abs_diff_eq(Vect::X, 1.0e-5));
+        assert!((shape.radius() - 1.25_f32.sqrt()).abs() < 1.0e-5);
+
+        // The caps of the capsule mesh are polygons, so the fitted capsule is slightly longer.
+        let capsule = fit(
+            Capsule2d::new(0.5, 1.0).mesh().resolution(128).build(),
+            ComputedColliderShape::Capsule { margin: 0.0 },
+        );
+        let capsule = capsule.as_capsule().unwrap();
+        assert!((capsule.radius() - 0.5).abs() < 1.0e-3);
+        assert!((capsule.half_height() - 0.5).abs() < 1.0e-2);
+    }
+}
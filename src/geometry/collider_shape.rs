@@ -0,0 +1,544 @@
+use bevy::prelude::*;
+
+use super::{Collider, ColliderView};
+use crate::math::{Real, Rot, Vect};
+
+/// A reflectable description of the shape of a [`Collider`].
+///
+/// [`Collider`] wraps a Rapier shape which can't be reflected, so it can't be saved in a
+/// `DynamicScene`, authored in a `.scn.ron` file, or edited from an inspector. Add this
+/// component instead (or alongside): whenever it is added or modified, the [`Collider`] of the
+/// same entity is rebuilt from it, keeping the scale currently applied to the collider.
+///
+/// The description of an existing collider can be obtained with [`ColliderShape::from_collider`],
+/// for example before saving a scene.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[reflect(Component, Default, Debug, PartialEq)]
+// `ColliderShape` is recursive through `Compound`.
+#[reflect(no_field_bounds)]
+pub enum ColliderShape {
+    /// A ball, see [`Collider::ball`].
+    Ball {
+        /// The radius of the ball.
+        radius: Real,
+    },
+    /// A cuboid, see [`Collider::cuboid`].
+    Cuboid {
+        /// The half-extents of the cuboid along each local axis.
+        half_extents: Vect,
+    },
+    /// A cuboid with rounded corners, see [`Collider::round_cuboid`].
+    RoundCuboid {
+        /// The half-extents of the cuboid along each local axis, without the border.
+        half_extents: Vect,
+        /// The radius of the round border.
+        border_radius: Real,
+    },
+    /// A capsule, see [`Collider::capsule`].
+    Capsule {
+        /// The first endpoint of the capsule's central segment.
+        a: Vect,
+        /// The second endpoint of the capsule's central segment.
+        b: Vect,
+        /// The radius of the capsule.
+        radius: Real,
+    },
+    /// A segment, see [`Collider::segment`].
+    Segment {
+        /// The first endpoint of the segment.
+        a: Vect,
+        /// The second endpoint of the segment.
+        b: Vect,
+    },
+    /// A triangle, see [`Collider::triangle`].
+    Triangle {
+        /// The first vertex of the triangle.
+        a: Vect,
+        /// The second vertex of the triangle.
+        b: Vect,
+        /// The third vertex of the triangle.
+        c: Vect,
+    },
+    /// A triangle with rounded corners, see [`Collider::round_triangle`].
+    RoundTriangle {
+        /// The first vertex of the triangle.
+        a: Vect,
+        /// The second vertex of the triangle.
+        b: Vect,
+        /// The third vertex of the triangle.
+        c: Vect,
+        /// The radius of the round border.
+        border_radius: Real,
+    },
+    /// A cylinder, see [`Collider::cylinder`].
+    #[cfg(feature = "dim3")]
+    Cylinder {
+        /// The half-height of the cylinder, along the local `y` axis.
+        half_height: Real,
+        /// The radius of the cylinder.
+        radius: Real,
+    },
+    /// A cylinder with rounded corners, see [`Collider::round_cylinder`].
+    #[cfg(feature = "dim3")]
+    RoundCylinder {
+        /// The half-height of the cylinder, along the local `y` axis.
+        half_height: Real,
+        /// The radius of the cylinder.
+        radius: Real,
+        /// The radius of the round border.
+        border_radius: Real,
+    },
+    /// A cone, see [`Collider::cone`].
+    #[cfg(feature = "dim3")]
+    Cone {
+        /// The half-height of the cone, along the local `y` axis.
+        half_height: Real,
+        /// The radius of the cone's base.
+        radius: Real,
+    },
+    /// A cone with rounded corners, see [`Collider::round_cone`].
+    #[cfg(feature = "dim3")]
+    RoundCone {
+        /// The half-height of the cone, along the local `y` axis.
+        half_height: Real,
+        /// The radius of the cone's base.
+        radius: Real,
+        /// The radius of the round border.
+        border_radius: Real,
+    },
+    /// The convex hull of a set of points, see [`Collider::convex_hull`].
+    ConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Vect>,
+    },
+    /// The convex hull of a set of points with rounded corners, see [`Collider::round_convex_hull`].
+    RoundConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Vect>,
+        /// The radius of the round border.
+        border_radius: Real,
+    },
+    /// A set of segments, see [`Collider::polyline`].
+    Polyline {
+        /// The vertex buffer of the polyline.
+        vertices: Vec<Vect>,
+        /// The index buffer of the polyline. If `None`, the vertices are linked in order.
+        indices: Option<Vec<[u32; 2]>>,
+    },
+    /// A triangle mesh, see [`Collider::trimesh`].
+    TriMesh {
+        /// The vertex buffer of the triangle mesh.
+        vertices: Vec<Vect>,
+        /// The index buffer of the triangle mesh.
+        indices: Vec<[u32; 3]>,
+    },
+    /// A heightfield, see [`Collider::heightfield`].
+    #[cfg(feature = "dim2")]
+    HeightField {
+        /// The heights of the heightfield.
+        heights: Vec<Real>,
+        /// The scale factor applied to the heightfield along each axis.
+        scale: Vect,
+    },
+    /// A heightfield, see [`Collider::heightfield`].
+    #[cfg(feature = "dim3")]
+    HeightField {
+        /// The heights of the heightfield, in column-major format.
+        heights: Vec<Real>,
+        /// The number of rows of `heights`.
+        num_rows: usize,
+        /// The number of columns of `heights`.
+        num_cols: usize,
+        /// The scale factor applied to the heightfield along each axis.
+        scale: Vect,
+    },
+    /// A half-space, see [`Collider::halfspace`].
+    HalfSpace {
+        /// The outward normal of the half-space.
+        outward_normal: Vect,
+    },
+    /// A compound shape, see [`Collider::compound`].
+    Compound {
+        /// The sub-shapes, with their position and rotation relative to the collider.
+        shapes: Vec<(Vect, Rot, ColliderShape)>,
+    },
+}
+
+impl Default for ColliderShape {
+    fn default() -> Self {
+        Self::Ball { radius: 0.5 }
+    }
+}
+
+impl ColliderShape {
+    /// Describes the unscaled shape of the given collider.
+    ///
+    /// Returns `None` if the shape has no reflectable description (voxels or custom shapes).
+    /// Triangle meshes are described by their vertex and index buffers only: the
+    /// [`TriMeshFlags`](crate::geometry::TriMeshFlags) they were built with aren't preserved.
+    pub fn from_collider(collider: &Collider) -> Option<Self> {
+        Self::from_view(collider.as_unscaled_typed_shape())
+    }
+
+    fn from_view(view: ColliderView) -> Option<Self> {
+        let shape = match view {
+            ColliderView::Ball(s) => Self::Ball { radius: s.radius() },
+            ColliderView::Cuboid(s) => Self::Cuboid {
+                half_extents: s.half_extents(),
+            },
+            ColliderView::RoundCuboid(s) => Self::RoundCuboid {
+                half_extents: s.inner_shape().half_extents(),
+                border_radius: s.border_radius(),
+            },
+            ColliderView::Capsule(s) => Self::Capsule {
+                a: s.segment().a(),
+                b: s.segment().b(),
+                radius: s.radius(),
+            },
+            ColliderView::Segment(s) => Self::Segment { a: s.a(), b: s.b() },
+            ColliderView::Triangle(s) => Self::Triangle {
+                a: s.a(),
+                b: s.b(),
+                c: s.c(),
+            },
+            ColliderView::RoundTriangle(s) => {
+                let [a, b, c] = s.inner_shape().vertices();
+                Self::RoundTriangle {
+                    a,
+                    b,
+                    c,
+                    border_radius: s.border_radius(),
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ColliderView::Cylinder(s) => Self::Cylinder {
+                half_height: s.half_height(),
+                radius: s.radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundCylinder(s) => Self::RoundCylinder {
+                half_height: s.inner_shape().half_height(),
+                radius: s.inner_shape().radius(),
+                border_radius: s.border_radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::Cone(s) => Self::Cone {
+                half_height: s.half_height(),
+                radius: s.radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundCone(s) => Self::RoundCone {
+                half_height: s.inner_shape().half_height(),
+                radius: s.inner_shape().radius(),
+                border_radius: s.border_radius(),
+            },
+            #[cfg(feature = "dim2")]
+            ColliderView::ConvexPolygon(s) => Self::ConvexHull {
+                points: s.points().collect(),
+            },
+            #[cfg(feature = "dim2")]
+            ColliderView::RoundConvexPolygon(s) => Self::RoundConvexHull {
+                points: s.inner_shape().points().collect(),
+                border_radius: s.border_radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::ConvexPolyhedron(s) => Self::ConvexHull {
+                points: s.points().collect(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundConvexPolyhedron(s) => Self::RoundConvexHull {
+                points: s.inner_shape().points().collect(),
+                border_radius: s.border_radius(),
+            },
+            ColliderView::Polyline(s) => Self::Polyline {
+                vertices: s.vertices().collect(),
+                indices: Some(s.indices().to_vec()),
+            },
+            ColliderView::TriMesh(s) => Self::TriMesh {
+                vertices: s.vertices().collect(),
+                indices: s.indices().to_vec(),
+            },
+            #[cfg(feature = "dim2")]
+            ColliderView::HeightField(s) => Self::HeightField {
+                heights: s.heights().to_vec(),
+                scale: s.scale(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::HeightField(s) => Self::HeightField {
+                heights: s.heights().to_vec(),
+                // The view counts cells, not heights.
+                num_rows: s.nrows() + 1,
+                num_cols: s.ncols() + 1,
+                scale: s.scale(),
+            },
+            ColliderView::HalfSpace(s) => Self::HalfSpace {
+                outward_normal: s.normal(),
+            },
+            ColliderView::Compound(s) => Self::Compound {
+                shapes: s
+                    .shapes()
+                    .map(|(translation, rotation, shape)| {
+                        Some((translation, rotation, Self::from_view(shape)?))
+                    })
+                    .collect::<Option<_>>()?,
+            },
+            ColliderView::Voxels(_) => return None,
+        };
+        Some(shape)
+    }
+
+    /// Builds an unscaled collider with the described shape.
+    ///
+    /// Returns `None` if the description is invalid, e.g. if a convex hull couldn't be computed
+    /// from its points, or if a triangle mesh has no triangles.
+    pub fn to_collider(&self) -> Option<Collider> {
+        let collider = match self {
+            Self::Ball { radius } => Collider::ball(*radius),
+            #[cfg(feature = "dim2")]
+            Self::Cuboid { half_extents } => Collider::cuboid(half_extents.x, half_extents.y),
+            #[cfg(feature = "dim3")]
+            Self::Cuboid { half_extents } => {
+                Collider::cuboid(half_extents.x, half_extents.y, half_extents.z)
+            }
+            #[cfg(feature = "dim2")]
+            Self::RoundCuboid {
+                half_extents,
+                border_radius,
+            } => Collider::round_cuboid(half_extents.x, half_extents.y, *border_radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCuboid {
+                half_extents,
+                border_radius,
+            } => Collider::round_cuboid(
+                half_extents.x,
+                half_extents.y,
+                half_extents.z,
+                *border_radius,
+            ),
+            Self::Capsule { a, b, radius } => Collider::capsule(*a, *b, *radius),
+            Self::Segment { a, b } => Collider::segment(*a, *b),
+            Self::Triangle { a, b, c } => Collider::triangle(*a, *b, *c),
+            Self::RoundTriangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } => Collider::round_triangle(*a, *b, *c, *border_radius),
+            #[cfg(feature = "dim3")]
+            Self::Cylinder {
+                half_height,
+                radius,
+            } => Collider::cylinder(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCylinder {
+                half_height,
+                radius,
+                border_radius,
+            } => Collider::round_cylinder(*half_height, *radius, *border_radius),
+            #[cfg(feature = "dim3")]
+            Self::Cone {
+                half_height,
+                radius,
+            } => Collider::cone(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCone {
+                half_height,
+                radius,
+                border_radius,
+            } => Collider::round_cone(*half_height, *radius, *border_radius),
+            // parry panics when computing the 2D convex hull of less than two points.
+            Self::ConvexHull { points } | Self::RoundConvexHull { points, .. }
+                if points.len() < 2 =>
+            {
+                return None
+            }
+            Self::ConvexHull { points } => Collider::convex_hull(points)?,
+            Self::RoundConvexHull {
+                points,
+                border_radius,
+            } => Collider::round_convex_hull(points, *border_radius)?,
+            Self::Polyline { vertices, indices } => {
+                Collider::polyline(vertices.clone(), indices.clone())
+            }
+            Self::TriMesh { vertices, indices } => {
+                Collider::trimesh(vertices.clone(), indices.clone()).ok()?
+            }
+            #[cfg(feature = "dim2")]
+            Self::HeightField { heights, scale } => Collider::heightfield(heights.clone(), *scale),
+            #[cfg(feature = "dim3")]
+            Self::HeightField {
+                heights,
+                num_rows,
+                num_cols,
+                scale,
+            } => {
+                if heights.len() != num_rows * num_cols {
+                    return None;
+                }
+                Collider::heightfield(heights.clone(), *num_rows, *num_cols, *scale)
+            }
+            Self::HalfSpace { outward_normal } => Collider::halfspace(*outward_normal)?,
+            Self::Compound { shapes } => Collider::compound(
+                shapes
+                    .iter()
+                    .map(|(translation, rotation, shape)| {
+                        Some((*translation, *rotation, shape.to_collider()?))
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+        };
+        Some(collider)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::time::TimePlugin;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn shape_description_roundtrip() {
+        #[cfg(feature = "dim2")]
+        let shapes = [
+            ColliderShape::Cuboid {
+                half_extents: Vect::new(1.0, 2.0),
+            },
+            ColliderShape::Capsule {
+                a: Vect::new(0.0, -1.0),
+                b: Vect::new(0.0, 1.0),
+                radius: 0.25,
+            },
+            ColliderShape::HeightField {
+                heights: vec![0.0, 1.0, 0.5, 2.0],
+                scale: Vect::new(4.0, 1.0),
+            },
+        ];
+        #[cfg(feature = "dim3")]
+        let shapes = [
+            ColliderShape::Cuboid {
+                half_extents: Vect::new(1.0, 2.0, 3.0),
+            },
+            ColliderShape::RoundCylinder {
+                half_height: 1.0,
+                radius: 0.5,
+                border_radius: 0.1,
+            },
+            ColliderShape::HeightField {
+                heights: vec![0.0, 1.0, 0.5, 2.0, 1.5, 0.0],
+                num_rows: 2,
+                num_cols: 3,
+                scale: Vect::new(4.0, 1.0, 4.0),
+            },
+        ];
+
+        for shape in &shapes {
+            let collider = shape.to_collider().unwrap();
+            assert_eq!(
+                ColliderShape::from_collider(&collider).as_ref(),
+                Some(shape)
+            );
+        }
+
+        // Compound shapes can't contain other composite shapes like heightfields.
+        let compound = ColliderShape::Compound {
+            shapes: vec![
+                (Vect::ONE, Rot::default(), shapes[0].clone()),
+                (-Vect::ONE, Rot::default(), shapes[1].clone()),
+            ],
+        };
+        let collider = compound.to_collider().unwrap();
+        assert_eq!(ColliderShape::from_collider(&collider), Some(compound));
+    }
+
+    #[test]
+    #[cfg(all(feature = "async-collider", feature = "serde-serialize"))]
+    fn shape_dynamic_scene_roundtrip() {
+        use bevy::scene::{ron, serde::SceneDeserializer, DynamicSceneBuilder};
+        use serde::de::DeserializeSeed;
+
+        let shape = ColliderShape::Compound {
+            shapes: vec![
+                (
+                    Vect::ONE,
+                    Rot::default(),
+                    ColliderShape::Ball { radius: 0.5 },
+                ),
+                (
+                    -Vect::ONE,
+                    Rot::default(),
+                    ColliderShape::Cuboid {
+                        half_extents: Vect::splat(2.0),
+                    },
+                ),
+            ],
+        };
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<ColliderShape>();
+        world.insert_resource(registry.clone());
+        let entity = world.spawn(shape.clone()).id();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(entity)
+            .build();
+        let serialized = scene.serialize(&registry.read()).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+        let mut loaded_world = World::new();
+        loaded_world.insert_resource(registry);
+        let mut entity_map = Default::default();
+        scene
+            .write_to_world(&mut loaded_world, &mut entity_map)
+            .unwrap();
+
+        let loaded = loaded_world
+            .query::<&ColliderShape>()
+            .single(&loaded_world)
+            .unwrap();
+        assert_eq!(loaded, &shape);
+    }
+
+    #[test]
+    fn collider_is_rebuilt_from_shape() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.finish();
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_scale(Vec3::splat(2.0)),
+                ColliderShape::Ball { radius: 0.5 },
+            ))
+            .id();
+        app.update();
+
+        let collider = app.world().get::<Collider>(entity).unwrap();
+        assert_eq!(
+            ColliderShape::from_collider(collider),
+            Some(ColliderShape::Ball { radius: 0.5 })
+        );
+        assert_eq!(collider.as_ball().unwrap().radius(), 1.0);
+
+        *app.world_mut().get_mut::<ColliderShape>(entity).unwrap() =
+            ColliderShape::Ball { radius: 1.5 };
+        app.update();
+
+        let collider = app.world().get::<Collider>(entity).unwrap();
+        assert_eq!(collider.scale(), Vect::splat(2.0));
+        assert_eq!(collider.as_ball().unwrap().radius(), 3.0);
+    }
+}
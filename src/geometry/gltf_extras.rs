@@ -0,0 +1,259 @@
+use bevy::gltf::{GltfExtras, GltfMeshExtras};
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer};
+
+use crate::dynamics::RigidBody;
+use crate::geometry::{
+    CollisionGroups, ComputedColliderShape, Friction, Group, Restitution, Sensor, TriMeshFlags,
+};
+use crate::math::Real;
+
+#[cfg(doc)]
+use crate::geometry::AsyncSceneCollider;
+
+/// The physics properties read from the glTF extras of a scene node or mesh, when
+/// [`AsyncSceneCollider::use_gltf_extras`] is enabled.
+///
+/// The extras are expected to be a JSON object, as exported by Blender for the custom properties
+/// of objects and meshes. The keys read by `bevy_rapier` are prefixed with `rapier_`, other keys
+/// are ignored:
+///
+/// ```json
+/// {
+///     "rapier_shape": "convex_hull",
+///     "rapier_sensor": false,
+///     "rapier_memberships": 1,
+///     "rapier_filters": 4294967295,
+///     "rapier_friction": 0.5,
+///     "rapier_restitution": 0.2,
+///     "rapier_rigid_body": "dynamic"
+/// }
+/// ```
+///
+/// For each mesh, the extras of the node are applied over the extras of its primitive, which are
+/// applied over the extras of the mesh itself. The rigid-body is inserted on the entity whose
+/// extras declare it, so a rigid-body declared on a node is shared by all its meshes.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct GltfColliderExtras {
+    /// The shape computed from the mesh, overriding the shapes of the [`AsyncSceneCollider`].
+    #[serde(rename = "rapier_shape")]
+    pub shape: Option<GltfColliderShape>,
+    /// Whether the collider is a [`Sensor`]. Both booleans and integers are accepted.
+    #[serde(rename = "rapier_sensor", deserialize_with = "bool_or_int")]
+    pub sensor: Option<bool>,
+    /// The memberships of the [`CollisionGroups`] of the collider.
+    #[serde(rename = "rapier_memberships")]
+    pub memberships: Option<u32>,
+    /// The filters of the [`CollisionGroups`] of the collider.
+    #[serde(rename = "rapier_filters")]
+    pub filters: Option<u32>,
+    /// The [`Friction`] coefficient of the collider.
+    #[serde(rename = "rapier_friction")]
+    pub friction: Option<Real>,
+    /// The [`Restitution`] coefficient of the collider.
+    #[serde(rename = "rapier_restitution")]
+    pub restitution: Option<Real>,
+    /// The type of the [`RigidBody`] inserted on the entity holding the extras.
+    #[serde(rename = "rapier_rigid_body")]
+    pub rigid_body: Option<GltfRigidBody>,
+}
+
+/// The shape of a collider declared in glTF extras, see [`GltfColliderExtras`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GltfColliderShape {
+    /// A [`ComputedColliderShape::TriMesh`] with merged duplicate vertices.
+    Trimesh,
+    /// A [`ComputedColliderShape::ConvexHull`].
+    ConvexHull,
+    /// A [`ComputedColliderShape::ConvexDecomposition`] with the default parameters.
+    ConvexDecomposition,
+    /// No collider is created for the mesh.
+    None,
+}
+
+impl GltfColliderShape {
+    /// The shape computed from the mesh, or `None` if no collider should be created.
+    pub fn computed_shape(self) -> Option<ComputedColliderShape> {
+        match self {
+            Self::Trimesh => Some(ComputedColliderShape::TriMesh(
+                TriMeshFlags::MERGE_DUPLICATE_VERTICES,
+            )),
+            Self::ConvexHull => Some(ComputedColliderShape::ConvexHull),
+            Self::ConvexDecomposition => Some(ComputedColliderShape::ConvexDecomposition(
+                Default::default(),
+            )),
+            Self::None => None,
+        }
+    }
+}
+
+/// The type of a rigid-body declared in glTF extras, see [`GltfColliderExtras`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GltfRigidBody {
+    /// A [`RigidBody::Dynamic`].
+    Dynamic,
+    /// A [`RigidBody::Fixed`].
+    Fixed,
+    /// A [`RigidBody::KinematicPositionBased`].
+    KinematicPositionBased,
+    /// A [`RigidBody::KinematicVelocityBased`].
+    KinematicVelocityBased,
+}
+
+impl From<GltfRigidBody> for RigidBody {
+    fn from(rigid_body: GltfRigidBody) -> Self {
+        match rigid_body {
+            GltfRigidBody::Dynamic => RigidBody::Dynamic,
+            GltfRigidBody::Fixed => RigidBody::Fixed,
+            GltfRigidBody::KinematicPositionBased => RigidBody::KinematicPositionBased,
+            GltfRigidBody::KinematicVelocityBased => RigidBody::KinematicVelocityBased,
+        }
+    }
+}
+
+fn bool_or_int<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<bool>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrInt {
+        Bool(bool),
+        Int(i64),
+    }
+
+    Ok(
+        Option::<BoolOrInt>::deserialize(deserializer)?.map(|value| match value {
+            BoolOrInt::Bool(value) => value,
+            BoolOrInt::Int(value) => value != 0,
+        }),
+    )
+}
+
+impl GltfColliderExtras {
+    /// Parses the given glTF extras, logging a warning if they aren't a valid JSON object.
+    pub fn parse(extras: &str) -> Self {
+        serde_json::from_str(extras).unwrap_or_else(|err| {
+            log::warn!("Ignoring invalid rapier properties in glTF extras {extras}: {err}");
+            Self::default()
+        })
+    }
+
+    /// Returns `self` with the properties defined in `other` replaced by the ones of `other`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            shape: other.shape.or(self.shape),
+            sensor: other.sensor.or(self.sensor),
+            memberships: other.memberships.or(self.memberships),
+            filters: other.filters.or(self.filters),
+            friction: other.friction.or(self.friction),
+            restitution: other.restitution.or(self.restitution),
+            rigid_body: other.rigid_body.or(self.rigid_body),
+        }
+    }
+
+    /// Inserts the collider properties defined by these extras on the given entity.
+    ///
+    /// The rigid-body isn't inserted, since it is usually declared on another entity than the
+    /// collider.
+    pub fn insert_collider_properties(&self, entity: &mut EntityCommands) {
+        if self.sensor == Some(true) {
+            entity.insert(Sensor);
+        }
+        if self.memberships.is_some() || self.filters.is_some() {
+            let default = CollisionGroups::default();
+            entity.insert(CollisionGroups::new(
+                self.memberships
+                    .map_or(default.memberships, Group::from_bits_retain),
+                self.filters
+                    .map_or(default.filters, Group::from_bits_retain),
+            ));
+        }
+        if let Some(friction) = self.friction {
+            entity.insert(Friction::new(friction));
+        }
+        if let Some(restitution) = self.restitution {
+            entity.insert(Restitution::new(restitution));
+        }
+    }
+}
+
+/// The glTF extras of the mesh entities, and of their parent node.
+pub(crate) type GltfExtrasQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Option<&'static GltfExtras>,
+        Option<&'static GltfMeshExtras>,
+        Option<&'static ChildOf>,
+    ),
+>;
+
+/// Reads the extras of a glTF mesh entity and of its parent node, and inserts the rigid-bodies
+/// they declare.
+///
+/// Returns the extras of the mesh with the ones of its node applied over them.
+pub(crate) fn read_gltf_extras(
+    commands: &mut Commands,
+    mesh_entity: Entity,
+    extras: &GltfExtrasQuery,
+) -> GltfColliderExtras {
+    let Ok((primitive_extras, mesh_extras, parent)) = extras.get(mesh_entity) else {
+        return GltfColliderExtras::default();
+    };
+
+    let parse = |extras: Option<&String>| {
+        extras.map_or_else(Default::default, |extras| GltfColliderExtras::parse(extras))
+    };
+    let mut result = parse(mesh_extras.map(|extras| &extras.value))
+        .merge(parse(primitive_extras.map(|extras| &extras.value)));
+    if let Some(rigid_body) = result.rigid_body {
+        commands
+            .entity(mesh_entity)
+            .insert(RigidBody::from(rigid_body));
+    }
+
+    if let Some(parent) = parent {
+        if let Ok((Some(node_extras), _, _)) = extras.get(parent.parent()) {
+            let node = GltfColliderExtras::parse(&node_extras.value);
+            if let Some(rigid_body) = node.rigid_body {
+                commands
+                    .entity(parent.parent())
+                    .insert(RigidBody::from(rigid_body));
+            }
+            result = result.merge(node);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gltf_extras_are_parsed_and_merged() {
+        let mesh = GltfColliderExtras::parse(
+            r#"{ "rapier_shape": "trimesh", "rapier_sensor": 1, "rapier_friction": 0.5, "color": "red" }"#,
+        );
+        assert_eq!(mesh.shape, Some(GltfColliderShape::Trimesh));
+        assert_eq!(mesh.sensor, Some(true));
+        assert_eq!(mesh.friction, Some(0.5));
+
+        let node = GltfColliderExtras::parse(
+            r#"{ "rapier_shape": "convex_hull", "rapier_memberships": 2, "rapier_rigid_body": "fixed" }"#,
+        );
+        let merged = mesh.merge(node);
+        assert_eq!(merged.shape, Some(GltfColliderShape::ConvexHull));
+        assert_eq!(merged.sensor, Some(true));
+        assert_eq!(merged.memberships, Some(2));
+        assert_eq!(merged.rigid_body, Some(GltfRigidBody::Fixed));
+
+        assert_eq!(
+            GltfColliderExtras::parse(r#"{ "rapier_shape": "sphere" }"#),
+            GltfColliderExtras::default(),
+            "Invalid extras should be ignored"
+        );
+    }
+}
@@ -0,0 +1,1176 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::ecs::system::{EntityCommands, SystemParam};
+use bevy::gltf::{Gltf, GltfMesh};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use rapier::dynamics::{JointAxesMask, JointAxis};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::dynamics::{
+    AdditionalMassProperties, CoefficientCombineRule, GenericJoint, GenericJointBuilder,
+    GravityScale, ImpulseJoint, MassProperties, RigidBody, TypedJoint, Velocity,
+};
+use crate::geometry::{
+    AsyncColliderStatus, AsyncColliderTask, Collider, ColliderMassProperties, CollisionGroups,
+    ComputedColliderShape, Friction, Group, MeshColliderCache, Restitution, Sensor, TriMeshFlags,
+};
+use crate::math::{Real, Rot, Vect};
+
+#[cfg(doc)]
+use crate::geometry::AsyncSceneCollider;
+
+const KHR_RIGID_BODIES: &str = "KHR_physics_rigid_bodies";
+const KHR_IMPLICIT_SHAPES: &str = "KHR_implicit_shapes";
+const OMI_BODY: &str = "OMI_physics_body";
+const OMI_SHAPE: &str = "OMI_physics_shape";
+const OMI_JOINT: &str = "OMI_physics_joint";
+
+/// The number of segments used to approximate the circles of a cylinder with different top and
+/// bottom radii.
+const FRUSTUM_SUBDIVISIONS: usize = 16;
+
+/// The physics extension a node or shape was read from, which defines the default values and the
+/// meaning of some properties.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Extension {
+    Khr,
+    Omi,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ShapeDesc {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(rename = "box")]
+    cuboid: Option<ShapeParams>,
+    sphere: Option<ShapeParams>,
+    capsule: Option<ShapeParams>,
+    cylinder: Option<ShapeParams>,
+    convex: Option<ShapeParams>,
+    trimesh: Option<ShapeParams>,
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ShapeParams {
+    size: Option<[Real; 3]>,
+    radius: Option<Real>,
+    radius_top: Option<Real>,
+    radius_bottom: Option<Real>,
+    height: Option<Real>,
+    mesh: Option<usize>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ShapeList {
+    shapes: Vec<ShapeDesc>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct KhrDocument {
+    physics_materials: Vec<PhysicsMaterial>,
+    collision_filters: Vec<CollisionFilter>,
+    physics_joints: Vec<JointDesc>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PhysicsMaterial {
+    static_friction: Option<Real>,
+    dynamic_friction: Option<Real>,
+    restitution: Option<Real>,
+    friction_combine: Option<String>,
+    restitution_combine: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct CollisionFilter {
+    collision_systems: Vec<String>,
+    collide_with_systems: Option<Vec<String>>,
+    not_collide_with_systems: Option<Vec<String>>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct JointDesc {
+    limits: Vec<JointLimitDesc>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct JointLimitDesc {
+    linear_axes: Vec<usize>,
+    angular_axes: Vec<usize>,
+    #[serde(alias = "lowerLimit")]
+    min: Option<Real>,
+    #[serde(alias = "upperLimit")]
+    max: Option<Real>,
+    stiffness: Option<Real>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct OmiJointDocument {
+    constraints: Vec<JointLimitDesc>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct NodeDesc {
+    motion: Option<MotionDesc>,
+    collider: Option<ColliderDesc>,
+    trigger: Option<ColliderDesc>,
+    joint: Option<KhrJointDesc>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct MotionDesc {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    is_kinematic: bool,
+    mass: Option<Real>,
+    center_of_mass: Option<[Real; 3]>,
+    inertia_diagonal: Option<[Real; 3]>,
+    inertia_orientation: Option<[Real; 4]>,
+    linear_velocity: Option<[Real; 3]>,
+    angular_velocity: Option<[Real; 3]>,
+    gravity_factor: Option<Real>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct ColliderDesc {
+    geometry: Option<GeometryDesc>,
+    shape: Option<usize>,
+    physics_material: Option<usize>,
+    collision_filter: Option<usize>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct GeometryDesc {
+    shape: Option<usize>,
+    mesh: Option<usize>,
+    convex_hull: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KhrJointDesc {
+    connected_node: usize,
+    joint: usize,
+    #[serde(default)]
+    enable_collision: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OmiNodeJointDesc {
+    node_a: usize,
+    node_b: usize,
+    #[serde(default)]
+    constraints: Vec<usize>,
+}
+
+/// The collider of a glTF node.
+pub(crate) enum NodeCollider {
+    /// A collider built from an implicit shape.
+    Shape(Collider),
+    /// A collider computed from the glTF mesh with the given index.
+    Mesh(usize, ComputedColliderShape),
+}
+
+/// Keeps alive the mesh the collider of a glTF node is computed from, which may be merged from the
+/// primitives of a glTF mesh.
+#[derive(Component)]
+pub(crate) struct GltfColliderMesh(#[allow(dead_code)] Handle<Mesh>);
+
+/// The components read from the physics extensions of a glTF node.
+#[derive(Default)]
+pub(crate) struct GltfNodePhysics {
+    rigid_body: Option<RigidBody>,
+    velocity: Option<Velocity>,
+    gravity_scale: Option<GravityScale>,
+    additional_mass: Option<AdditionalMassProperties>,
+    collider: Option<NodeCollider>,
+    sensor: bool,
+    collider_mass: Option<ColliderMassProperties>,
+    friction: Option<Friction>,
+    restitution: Option<Restitution>,
+    collision_groups: Option<CollisionGroups>,
+    /// The joint attached to this node, with the index of the node of its first rigid-body.
+    joint: Option<(usize, GenericJoint)>,
+}
+
+impl GltfNodePhysics {
+    fn is_empty(&self) -> bool {
+        self.rigid_body.is_none() && self.collider.is_none() && self.joint.is_none()
+    }
+
+    /// Inserts the components on the entity of the node. The mesh colliders must be requested
+    /// beforehand, see [`GltfPhysicsAssets::import`].
+    fn insert_into(self, entity: &mut EntityCommands, node_entities: &HashMap<usize, Entity>) {
+        if let Some(rigid_body) = self.rigid_body {
+            entity.insert(rigid_body);
+        }
+        if let Some(velocity) = self.velocity {
+            entity.insert(velocity);
+        }
+        if let Some(gravity_scale) = self.gravity_scale {
+            entity.insert(gravity_scale);
+        }
+        if let Some(additional_mass) = self.additional_mass {
+            entity.insert(additional_mass);
+        }
+        if let Some(collider) = self.collider {
+            if let NodeCollider::Shape(collider) = collider {
+                entity.insert(collider);
+            }
+            if self.sensor {
+                entity.insert(Sensor);
+            }
+        }
+        if let Some(collider_mass) = self.collider_mass {
+            entity.insert(collider_mass);
+        }
+        if let Some(friction) = self.friction {
+            entity.insert(friction);
+        }
+        if let Some(restitution) = self.restitution {
+            entity.insert(restitution);
+        }
+        if let Some(collision_groups) = self.collision_groups {
+            entity.insert(collision_groups);
+        }
+        if let Some((parent_node, joint)) = self.joint {
+            match node_entities.get(&parent_node) {
+                Some(parent) => {
+                    entity.insert(ImpulseJoint::new(*parent, TypedJoint::GenericJoint(joint)));
+                }
+                None => log::warn!(
+                    "Ignoring the glTF joint of {}: the entity of node {parent_node} wasn't found.",
+                    entity.id()
+                ),
+            }
+        }
+    }
+}
+
+/// Deserializes an extension, logging a warning if it is invalid.
+fn parse<T: Default + DeserializeOwned>(name: &str, value: Option<&Value>) -> T {
+    value
+        .map(|value| {
+            T::deserialize(value).unwrap_or_else(|err| {
+                log::warn!("Ignoring invalid glTF extension {name}: {err}");
+                T::default()
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// The isometry of each node relative to the root of its scene, ignoring the scale of the node
+/// but not the scale of its ancestors.
+fn node_isometries(document: &gltf::Document) -> Vec<(Vect, Rot)> {
+    fn visit(node: gltf::Node, parent: &Transform, result: &mut [(Vect, Rot)]) {
+        let (translation, rotation, scale) = node.transform().decomposed();
+        let local = Transform {
+            translation: translation.into(),
+            rotation: Quat::from_array(rotation),
+            scale: scale.into(),
+        };
+        let global = parent.mul_transform(local);
+        result[node.index()] = (global.translation, global.rotation);
+        for child in node.children() {
+            visit(child, &global, result);
+        }
+    }
+
+    let mut result = vec![(Vect::ZERO, Rot::IDENTITY); document.nodes().len()];
+    let mut is_child = vec![false; document.nodes().len()];
+    for node in document.nodes() {
+        for child in node.children() {
+            is_child[child.index()] = true;
+        }
+    }
+    for node in document.nodes().filter(|node| !is_child[node.index()]) {
+        visit(node, &Transform::IDENTITY, &mut result);
+    }
+    result
+}
+
+fn shape_collider(shape: &ShapeDesc, extension: Extension) -> Option<NodeCollider> {
+    let params = |params: &Option<ShapeParams>| params.clone().unwrap_or_default();
+    // The default dimensions of the shapes differ between the two extensions.
+    let (default_radius, default_height) = match extension {
+        Extension::Khr => (0.25, 0.5),
+        Extension::Omi => (0.5, 2.0),
+    };
+
+    let collider = match shape.kind.as_str() {
+        "box" => {
+            let size = Vect::from(params(&shape.cuboid).size.unwrap_or([1.0; 3]));
+            Some(Collider::cuboid(size.x / 2.0, size.y / 2.0, size.z / 2.0))
+        }
+        "sphere" => Some(Collider::ball(params(&shape.sphere).radius.unwrap_or(0.5))),
+        "capsule" => {
+            let p = params(&shape.capsule);
+            let height = p.height.unwrap_or(default_height);
+            let (radius, half_height) = match extension {
+                // The height is the distance between the centers of the hemispheres.
+                Extension::Khr => {
+                    let top = p.radius_top.unwrap_or(default_radius);
+                    let bottom = p.radius_bottom.unwrap_or(default_radius);
+                    if top != bottom {
+                        log::warn!("Capsules with different radii aren't supported, using the largest radius.");
+                    }
+                    (top.max(bottom), height / 2.0)
+                }
+                // The height includes the hemispheres.
+                Extension::Omi => {
+                    let radius = p.radius.unwrap_or(default_radius);
+                    (radius, (height / 2.0 - radius).max(0.0))
+                }
+            };
+            Some(Collider::capsule_y(half_height, radius))
+        }
+        "cylinder" => {
+            let p = params(&shape.cylinder);
+            let half_height = p.height.unwrap_or(default_height) / 2.0;
+            let radius = p.radius.unwrap_or(default_radius);
+            let top = p.radius_top.unwrap_or(radius);
+            let bottom = p.radius_bottom.unwrap_or(radius);
+            if top == bottom {
+                Some(Collider::cylinder(half_height, top))
+            } else {
+                let points: Vec<_> = (0..FRUSTUM_SUBDIVISIONS)
+                    .flat_map(|i| {
+                        let angle =
+                            i as Real * std::f32::consts::TAU / FRUSTUM_SUBDIVISIONS as Real;
+                        let (sin, cos) = angle.sin_cos();
+                        [
+                            Vect::new(cos * top, half_height, sin * top),
+                            Vect::new(cos * bottom, -half_height, sin * bottom),
+                        ]
+                    })
+                    .collect();
+                Collider::convex_hull(&points)
+            }
+        }
+        "convex" => {
+            return params(&shape.convex)
+                .mesh
+                .map(|mesh| NodeCollider::Mesh(mesh, ComputedColliderShape::ConvexHull));
+        }
+        "trimesh" => {
+            return params(&shape.trimesh).mesh.map(|mesh| {
+                NodeCollider::Mesh(
+                    mesh,
+                    ComputedColliderShape::TriMesh(TriMeshFlags::MERGE_DUPLICATE_VERTICES),
+                )
+            });
+        }
+        kind => {
+            log::warn!("Ignoring unsupported glTF physics shape {kind:?}.");
+            None
+        }
+    };
+    collider.map(NodeCollider::Shape)
+}
+
+fn combine_rule(rule: &Option<String>) -> CoefficientCombineRule {
+    match rule.as_deref() {
+        Some("minimum") => CoefficientCombineRule::Min,
+        Some("maximum") => CoefficientCombineRule::Max,
+        Some("multiply") => CoefficientCombineRule::Multiply,
+        _ => CoefficientCombineRule::Average,
+    }
+}
+
+/// Converts the collision filters into collision groups, giving one bit to each collision system
+/// in their order of appearance.
+fn collision_groups(filters: &[CollisionFilter]) -> Vec<CollisionGroups> {
+    let mut systems: Vec<&str> = Vec::new();
+    for filter in filters {
+        let names = filter
+            .collision_systems
+            .iter()
+            .chain(filter.collide_with_systems.iter().flatten())
+            .chain(filter.not_collide_with_systems.iter().flatten());
+        for name in names {
+            if !systems.contains(&name.as_str()) {
+                systems.push(name);
+            }
+        }
+    }
+    if systems.len() > 32 {
+        log::warn!("glTF collision filters use more than 32 collision systems, the extra systems are ignored.");
+    }
+
+    let bits = |names: &[String]| {
+        names
+            .iter()
+            .filter_map(|name| systems.iter().position(|system| system == name))
+            .filter(|bit| *bit < 32)
+            .fold(Group::NONE, |group, bit| {
+                group | Group::from_bits_retain(1 << bit)
+            })
+    };
+
+    filters
+        .iter()
+        .map(|filter| {
+            let filters = match (
+                &filter.collide_with_systems,
+                &filter.not_collide_with_systems,
+            ) {
+                (Some(collide_with), _) => bits(collide_with),
+                (None, Some(not_collide_with)) => Group::ALL - bits(not_collide_with),
+                (None, None) => Group::ALL,
+            };
+            CollisionGroups::new(bits(&filter.collision_systems), filters)
+        })
+        .collect()
+}
+
+fn generic_joint(limits: &[&JointLimitDesc]) -> GenericJointBuilder {
+    const LINEAR: [JointAxis; 3] = [JointAxis::LinX, JointAxis::LinY, JointAxis::LinZ];
+    const ANGULAR: [JointAxis; 3] = [JointAxis::AngX, JointAxis::AngY, JointAxis::AngZ];
+
+    let mut locked_axes = JointAxesMask::empty();
+    let mut joint = GenericJointBuilder::new(JointAxesMask::empty());
+    for limit in limits {
+        let axes = limit
+            .linear_axes
+            .iter()
+            .filter_map(|axis| LINEAR.get(*axis))
+            .chain(
+                limit
+                    .angular_axes
+                    .iter()
+                    .filter_map(|axis| ANGULAR.get(*axis)),
+            );
+        let is_locked = limit.min == Some(0.0) && limit.max == Some(0.0);
+        for axis in axes {
+            if is_locked && limit.stiffness.is_none() {
+                locked_axes |= JointAxesMask::from(*axis);
+            } else if limit.min.is_some() || limit.max.is_some() {
+                joint = joint.limits(
+                    *axis,
+                    [
+                        limit.min.unwrap_or(-Real::MAX),
+                        limit.max.unwrap_or(Real::MAX),
+                    ],
+                );
+            }
+        }
+    }
+    joint.locked_axes(locked_axes)
+}
+
+/// Reads the components described by the `KHR_physics_rigid_bodies`, `KHR_implicit_shapes`,
+/// `OMI_physics_body`, `OMI_physics_shape` and `OMI_physics_joint` extensions of a glTF document,
+/// indexed by node.
+pub(crate) fn read_gltf_physics(document: &gltf::Document) -> HashMap<usize, GltfNodePhysics> {
+    let khr: KhrDocument = parse(KHR_RIGID_BODIES, document.extension_value(KHR_RIGID_BODIES));
+    let khr_shapes: ShapeList = parse(
+        KHR_IMPLICIT_SHAPES,
+        document.extension_value(KHR_IMPLICIT_SHAPES),
+    );
+    let omi_shapes: ShapeList = parse(OMI_SHAPE, document.extension_value(OMI_SHAPE));
+    let omi_joints: OmiJointDocument = parse(OMI_JOINT, document.extension_value(OMI_JOINT));
+    let khr_groups = collision_groups(&khr.collision_filters);
+
+    let nodes: Vec<Option<(Extension, NodeDesc)>> = document
+        .nodes()
+        .map(|node| {
+            if let Some(value) = node.extension_value(KHR_RIGID_BODIES) {
+                Some((Extension::Khr, parse(KHR_RIGID_BODIES, Some(value))))
+            } else {
+                node.extension_value(OMI_BODY)
+                    .map(|value| (Extension::Omi, parse(OMI_BODY, Some(value))))
+            }
+        })
+        .collect();
+
+    // The node of the rigid-body each node is attached to.
+    let mut parents = vec![None; nodes.len()];
+    for node in document.nodes() {
+        for child in node.children() {
+            parents[child.index()] = Some(node.index());
+        }
+    }
+    let body_of = |mut node: usize| loop {
+        let desc = nodes.get(node)?;
+        if desc.as_ref().is_some_and(|(_, desc)| desc.motion.is_some()) {
+            return Some(node);
+        }
+        node = (*parents.get(node)?)?;
+    };
+    let isometries = node_isometries(document);
+    let relative_frame = |body: usize, node: usize| {
+        let (body_translation, body_rotation) = isometries[body];
+        let (translation, rotation) = isometries[node];
+        let inv_rotation = body_rotation.inverse();
+        (
+            inv_rotation * (translation - body_translation),
+            inv_rotation * rotation,
+        )
+    };
+    let joint_between = |body1: usize, node1: usize, body2: usize, node2: usize| {
+        let (anchor1, basis1) = relative_frame(body1, node1);
+        let (anchor2, basis2) = relative_frame(body2, node2);
+        move |joint: GenericJointBuilder| {
+            joint
+                .local_anchor1(anchor1)
+                .local_basis1(basis1)
+                .local_anchor2(anchor2)
+                .local_basis2(basis2)
+                .build()
+        }
+    };
+
+    let mut result = HashMap::default();
+    for (index, (extension, desc)) in nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, node)| Some((index, node.as_ref()?)))
+    {
+        let mut physics = GltfNodePhysics::default();
+
+        if let Some(motion) = &desc.motion {
+            physics.rigid_body = Some(match motion.kind.as_deref() {
+                Some("static") => RigidBody::Fixed,
+                Some("kinematic") => RigidBody::KinematicPositionBased,
+                _ if motion.is_kinematic => RigidBody::KinematicPositionBased,
+                _ => RigidBody::Dynamic,
+            });
+            if motion.linear_velocity.is_some() || motion.angular_velocity.is_some() {
+                physics.velocity = Some(Velocity {
+                    linvel: motion.linear_velocity.map_or(Vect::ZERO, Vect::from),
+                    angvel: motion.angular_velocity.map_or(Vect::ZERO, Vect::from),
+                });
+            }
+            physics.gravity_scale = motion.gravity_factor.map(GravityScale);
+            if let Some(mass) = motion.mass {
+                let mass_properties = match motion.inertia_diagonal {
+                    Some(inertia) => ColliderMassProperties::MassProperties(MassProperties {
+                        local_center_of_mass: motion.center_of_mass.map_or(Vect::ZERO, Vect::from),
+                        mass,
+                        principal_inertia_local_frame: motion
+                            .inertia_orientation
+                            .map_or(Rot::IDENTITY, Rot::from_array),
+                        principal_inertia: inertia.into(),
+                    }),
+                    None => ColliderMassProperties::Mass(mass),
+                };
+                // Without a collider on the body node, the mass is added to the rigid-body.
+                if desc.collider.is_some() {
+                    physics.collider_mass = Some(mass_properties);
+                } else {
+                    physics.additional_mass = Some(match mass_properties {
+                        ColliderMassProperties::MassProperties(props) => {
+                            AdditionalMassProperties::MassProperties(props)
+                        }
+                        _ => AdditionalMassProperties::Mass(mass),
+                    });
+                }
+            }
+        }
+
+        let (collider, sensor) = match (&desc.collider, &desc.trigger) {
+            (Some(collider), _) => (Some(collider), false),
+            (None, trigger) => (trigger.as_ref(), true),
+        };
+        if let Some(collider) = collider {
+            let shapes = match extension {
+                Extension::Khr => &khr_shapes.shapes,
+                Extension::Omi => &omi_shapes.shapes,
+            };
+            let geometry = collider.geometry.as_ref();
+            physics.collider = match geometry.and_then(|geometry| geometry.mesh) {
+                Some(mesh) => {
+                    let shape = if geometry.is_some_and(|geometry| geometry.convex_hull) {
+                        ComputedColliderShape::ConvexHull
+                    } else {
+                        ComputedColliderShape::TriMesh(TriMeshFlags::MERGE_DUPLICATE_VERTICES)
+                    };
+                    Some(NodeCollider::Mesh(mesh, shape))
+                }
+                None => geometry
+                    .and_then(|geometry| geometry.shape)
+                    .or(collider.shape)
+                    .and_then(|shape| shapes.get(shape))
+                    .and_then(|shape| shape_collider(shape, *extension)),
+            };
+            if physics.collider.is_none() {
+                log::warn!("Could not build the collider of glTF node {index}.");
+            }
+            physics.sensor = sensor;
+
+            if let Some(material) = collider
+                .physics_material
+                .and_then(|material| khr.physics_materials.get(material))
+            {
+                if let Some(coefficient) = material.dynamic_friction.or(material.static_friction) {
+                    physics.friction = Some(Friction {
+                        coefficient,
+                        combine_rule: combine_rule(&material.friction_combine),
+                    });
+                }
+                if let Some(coefficient) = material.restitution {
+                    physics.restitution = Some(Restitution {
+                        coefficient,
+                        combine_rule: combine_rule(&material.restitution_combine),
+                    });
+                }
+            }
+            physics.collision_groups = collider
+                .collision_filter
+                .and_then(|filter| khr_groups.get(filter))
+                .copied();
+        }
+
+        if let Some(joint) = &desc.joint {
+            let desc = khr.physics_joints.get(joint.joint);
+            match (desc, body_of(index), body_of(joint.connected_node)) {
+                (Some(desc), Some(body), Some(connected_body)) => {
+                    let limits: Vec<_> = desc.limits.iter().collect();
+                    let mut data = joint_between(connected_body, joint.connected_node, body, index)(
+                        generic_joint(&limits),
+                    );
+                    data.set_contacts_enabled(joint.enable_collision);
+                    physics.joint = Some((connected_body, data));
+                }
+                _ => log::warn!("Ignoring the invalid joint of glTF node {index}."),
+            }
+        }
+
+        if !physics.is_empty() {
+            result.insert(index, physics);
+        }
+    }
+
+    // OMI joints are declared on a node of their own, and attached to the node of their second
+    // rigid-body.
+    for node in document.nodes() {
+        let Some(value) = node.extension_value(OMI_JOINT) else {
+            continue;
+        };
+        let Ok(joint) = OmiNodeJointDesc::deserialize(value) else {
+            log::warn!("Ignoring the invalid joint of glTF node {}.", node.index());
+            continue;
+        };
+        let (Some(body_a), Some(body_b)) = (body_of(joint.node_a), body_of(joint.node_b)) else {
+            log::warn!(
+                "Ignoring the joint of glTF node {}: its rigid-bodies weren't found.",
+                node.index()
+            );
+            continue;
+        };
+        let limits: Vec<_> = joint
+            .constraints
+            .iter()
+            .filter_map(|constraint| omi_joints.constraints.get(*constraint))
+            .collect();
+        let data =
+            joint_between(body_a, node.index(), body_b, node.index())(generic_joint(&limits));
+        let physics = result.entry(joint.node_b).or_default();
+        if physics.joint.is_some() {
+            log::warn!(
+                "glTF node {} has multiple joints, only the last one is kept.",
+                joint.node_b
+            );
+        }
+        physics.joint = Some((body_a, data));
+    }
+
+    result
+}
+
+/// The assets and entities used by the `init_async_scene_colliders` system to import the physics
+/// of a glTF scene, see [`AsyncSceneCollider::gltf_physics`](crate::geometry::AsyncSceneCollider::gltf_physics).
+#[derive(SystemParam)]
+pub struct GltfPhysicsAssets<'w, 's> {
+    asset_server: Option<Res<'w, AssetServer>>,
+    gltfs: Option<Res<'w, Assets<Gltf>>>,
+    gltf_meshes: Option<Res<'w, Assets<GltfMesh>>>,
+    nodes: Query<'w, 's, &'static Name, Without<Mesh3d>>,
+}
+
+impl GltfPhysicsAssets<'_, '_> {
+    /// Why the physics of the given glTF asset will never be importable, if it isn't.
+    pub(crate) fn import_error(&self, gltf: &Handle<Gltf>) -> Option<&'static str> {
+        if self.gltfs.is_none() {
+            return Some("the glTF assets don't exist, is the `GltfPlugin` added?");
+        }
+        let failed = self.asset_server.as_ref().is_some_and(|asset_server| {
+            asset_server
+                .get_load_state(gltf)
+                .is_some_and(|state| state.is_failed())
+        });
+        failed.then_some("the glTF asset failed to load")
+    }
+
+    /// Whether the given glTF asset is loaded, so its physics can be imported.
+    pub(crate) fn is_loaded(&self, gltf: &Handle<Gltf>) -> bool {
+        self.gltfs
+            .as_ref()
+            .is_some_and(|gltfs| gltfs.contains(gltf))
+    }
+
+    /// Inserts the components described by the physics extensions of the given glTF asset on the
+    /// descendants of `scene_entity`.
+    ///
+    /// The entities of the glTF nodes are found by name, so the nodes with a physics extension
+    /// should have unique names. The colliders computed from meshes are requested from the
+    /// [`MeshColliderCache`], and inserted once computed.
+    pub(crate) fn import(
+        &self,
+        commands: &mut Commands,
+        gltf: &Handle<Gltf>,
+        scene_entity: Entity,
+        children: &Query<&Children>,
+        meshes: &mut Assets<Mesh>,
+        cache: &mut MeshColliderCache,
+    ) {
+        let Some(gltf) = self.gltfs.as_ref().and_then(|gltfs| gltfs.get(gltf)) else {
+            return;
+        };
+        let Some(source) = &gltf.source else {
+            log::error!(
+                "Could not import the physics of a glTF asset loaded without `GltfLoaderSettings::include_source`."
+            );
+            return;
+        };
+
+        let physics = read_gltf_physics(&source.document);
+        if physics.is_empty() {
+            return;
+        }
+
+        // Unnamed nodes are named after their index by the glTF loader.
+        let mut node_names: HashMap<String, Option<usize>> = HashMap::default();
+        for node in source.document.nodes() {
+            let name = node
+                .name()
+                .map_or_else(|| format!("GltfNode{}", node.index()), ToString::to_string);
+            node_names
+                .entry(name)
+                .and_modify(|index| *index = None)
+                .or_insert(Some(node.index()));
+        }
+        let node_entities: HashMap<usize, Entity> = children
+            .iter_descendants(scene_entity)
+            .filter_map(|entity| {
+                let index = (*node_names.get(self.nodes.get(entity).ok()?.as_str())?)?;
+                Some((index, entity))
+            })
+            .collect();
+
+        // The meshes merged from the primitives of each glTF mesh.
+        let mut merged_meshes: HashMap<usize, Handle<Mesh>> = HashMap::default();
+        for (index, node_physics) in physics {
+            let Some(entity) = node_entities.get(&index) else {
+                log::warn!(
+                    "Ignoring the physics of glTF node {index}: its entity wasn't found, or its name isn't unique."
+                );
+                continue;
+            };
+            let mut entity = commands.entity(*entity);
+            if let Some(NodeCollider::Mesh(mesh, shape)) = &node_physics.collider {
+                match self.collider_mesh(gltf, *mesh, meshes, &mut merged_meshes) {
+                    Some(handle) => {
+                        // The mesh is loaded, or was just merged from loaded primitives.
+                        cache.request(handle.id(), meshes.get(&handle).unwrap(), shape);
+                        entity.insert((
+                            AsyncColliderTask {
+                                mesh: handle.id(),
+                                shape: shape.clone(),
+                            },
+                            AsyncColliderStatus::Pending,
+                            GltfColliderMesh(handle),
+                        ));
+                    }
+                    None => log::warn!(
+                        "Could not build the collider of glTF node {index}: mesh {mesh} wasn't found."
+                    ),
+                }
+            }
+            node_physics.insert_into(&mut entity, &node_entities);
+        }
+    }
+
+    /// The mesh the collider of the glTF mesh with the given index is computed from.
+    ///
+    /// The primitives of a glTF mesh are separate meshes: they are merged into a single mesh, so
+    /// that a single triangle mesh or convex hull is computed from them. The meshes without
+    /// indices, which the glTF loader creates for the primitives without normals, are indexed.
+    fn collider_mesh(
+        &self,
+        gltf: &Gltf,
+        mesh: usize,
+        meshes: &mut Assets<Mesh>,
+        merged_meshes: &mut HashMap<usize, Handle<Mesh>>,
+    ) -> Option<Handle<Mesh>> {
+        let gltf_mesh = self.gltf_meshes.as_ref()?.get(gltf.meshes.get(mesh)?)?;
+        match gltf_mesh.primitives.as_slice() {
+            [] => None,
+            [primitive]
+                if meshes
+                    .get(&primitive.mesh)
+                    .is_some_and(|mesh| mesh.indices().is_some()) =>
+            {
+                Some(primitive.mesh.clone())
+            }
+            primitives => {
+                if let Some(merged) = merged_meshes.get(&mesh) {
+                    return Some(merged.clone());
+                }
+                let merged = merge_primitives(
+                    primitives
+                        .iter()
+                        .filter_map(|primitive| meshes.get(&primitive.mesh)),
+                );
+                let merged = meshes.add(merged);
+                merged_meshes.insert(mesh, merged.clone());
+                Some(merged)
+            }
+        }
+    }
+}
+
+/// Merges the positions and indices of the given triangle meshes into a single mesh.
+///
+/// The vertices of the meshes without indices are assumed to be a list of triangles.
+fn merge_primitives<'a>(primitives: impl IntoIterator<Item = &'a Mesh>) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    for primitive in primitives {
+        let vertices = primitive
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|vertices| vertices.as_float3());
+        let Some(vertices) = vertices else {
+            continue;
+        };
+        let offset = positions.len() as u32;
+        positions.extend_from_slice(vertices);
+        match primitive.indices() {
+            Some(primitive_indices) => {
+                indices.extend(primitive_indices.iter().map(|index| offset + index as u32));
+            }
+            None => indices.extend(offset..positions.len() as u32),
+        }
+    }
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::MAIN_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["KHR_physics_rigid_bodies", "KHR_implicit_shapes"],
+        "extensions": {
+            "KHR_implicit_shapes": {
+                "shapes": [
+                    { "type": "box", "box": { "size": [2.0, 4.0, 6.0] } },
+                    { "type": "sphere", "sphere": { "radius": 0.5 } }
+                ]
+            },
+            "KHR_physics_rigid_bodies": {
+                "physicsMaterials": [
+                    { "staticFriction": 0.8, "dynamicFriction": 0.6, "restitution": 0.1, "frictionCombine": "minimum" }
+                ],
+                "collisionFilters": [
+                    { "collisionSystems": ["player"], "notCollideWithSystems": ["ghost"] },
+                    { "collisionSystems": ["ghost"], "collideWithSystems": ["ghost"] }
+                ],
+                "physicsJoints": [
+                    { "limits": [
+                        { "linearAxes": [0, 1, 2], "min": 0.0, "max": 0.0 },
+                        { "angularAxes": [0], "min": -1.0, "max": 1.0 }
+                    ] }
+                ]
+            }
+        },
+        "scenes": [{ "nodes": [0, 1, 3, 5, 6] }],
+        "nodes": [
+            {
+                "name": "Ground",
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "collider": { "geometry": { "shape": 0 }, "physicsMaterial": 0, "collisionFilter": 0 }
+                } }
+            },
+            {
+                "name": "Ball",
+                "translation": [0.0, 5.0, 0.0],
+                "children": [2],
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "motion": { "mass": 2.0, "linearVelocity": [1.0, 0.0, 0.0] }
+                } }
+            },
+            {
+                "name": "BallShape",
+                "translation": [0.0, -0.5, 0.0],
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "trigger": { "geometry": { "shape": 1 }, "collisionFilter": 1 }
+                } }
+            },
+            {
+                "name": "Hinge",
+                "translation": [0.0, 3.0, 0.0],
+                "rotation": [0.0, 0.0, 0.7071068, 0.7071068],
+                "children": [4],
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "motion": { "isKinematic": true }
+                } }
+            },
+            {
+                "name": "HingeFrame",
+                "translation": [1.0, 0.0, 0.0],
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "joint": { "connectedNode": 2, "joint": 0 }
+                } }
+            },
+            {
+                "name": "Rock",
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "collider": { "geometry": { "mesh": 0 } }
+                } }
+            },
+            {
+                "name": "Boulder",
+                "extensions": { "KHR_physics_rigid_bodies": {
+                    "collider": { "geometry": { "mesh": 0, "convexHull": true } }
+                } }
+            }
+        ],
+        "meshes": [
+            { "primitives": [
+                { "attributes": { "POSITION": 0 }, "indices": 1 },
+                { "attributes": { "POSITION": 0 }, "indices": 2 }
+            ] }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3", "min": [0.0, 0.0, 0.0], "max": [1.0, 1.0, 1.0] },
+            { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" },
+            { "bufferView": 1, "byteOffset": 6, "componentType": 5123, "count": 3, "type": "SCALAR" }
+        ],
+        "bufferViews": [
+            { "buffer": 0, "byteLength": 48, "target": 34962 },
+            { "buffer": 0, "byteOffset": 48, "byteLength": 12, "target": 34963 }
+        ],
+        "buffers": [
+            { "byteLength": 60, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAACAPwAAAAAAAIA/AAAAAAAAgD8AAIA/AAABAAIAAAACAAMA" }
+        ]
+    }"#;
+
+    #[test]
+    fn gltf_physics_extensions_are_read() {
+        let gltf = gltf::Gltf::from_slice(DOCUMENT.as_bytes()).unwrap();
+        let physics = read_gltf_physics(&gltf.document);
+        let shape = |physics: &GltfNodePhysics| match &physics.collider {
+            Some(NodeCollider::Shape(collider)) => collider.clone(),
+            _ => panic!("The collider should be built from a shape"),
+        };
+
+        let ground = &physics[&0];
+        assert!(ground.rigid_body.is_none());
+        let half_extents = shape(ground).as_cuboid().unwrap().half_extents();
+        assert_eq!(half_extents, Vect::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            ground.friction,
+            Some(Friction {
+                coefficient: 0.6,
+                combine_rule: CoefficientCombineRule::Min
+            })
+        );
+        assert_eq!(ground.restitution.unwrap().coefficient, 0.1);
+        let groups = ground.collision_groups.unwrap();
+        assert_eq!(groups.memberships, Group::GROUP_1);
+        assert_eq!(groups.filters, Group::ALL - Group::GROUP_2);
+
+        let ball = &physics[&1];
+        assert_eq!(ball.rigid_body, Some(RigidBody::Dynamic));
+        assert_eq!(ball.velocity.unwrap().linvel, Vect::X);
+        assert_eq!(
+            ball.additional_mass,
+            Some(AdditionalMassProperties::Mass(2.0))
+        );
+
+        let ball_shape = &physics[&2];
+        assert!(ball_shape.sensor);
+        assert_eq!(shape(ball_shape).as_ball().unwrap().radius(), 0.5);
+        let groups = ball_shape.collision_groups.unwrap();
+        assert_eq!(
+            (groups.memberships, groups.filters),
+            (Group::GROUP_2, Group::GROUP_2)
+        );
+
+        let hinge = &physics[&3];
+        assert_eq!(hinge.rigid_body, Some(RigidBody::KinematicPositionBased));
+        assert!(hinge.joint.is_none());
+
+        let (parent, joint) = physics[&4].joint.unwrap();
+        assert_eq!(
+            parent, 1,
+            "The joint should be attached to the body of the ball"
+        );
+        assert_eq!(joint.locked_axes(), JointAxesMask::LIN_AXES);
+        assert_eq!(joint.limits(JointAxis::AngX).unwrap().min, -1.0);
+        assert_eq!(joint.local_anchor1(), Vect::new(0.0, -0.5, 0.0));
+        assert!(joint
+            .local_anchor2()
+            .abs_diff_eq(Vect::new(1.0, 0.0, 0.0), 1.0e-6));
+
+        assert!(matches!(
+            physics[&5].collider,
+            Some(NodeCollider::Mesh(0, ComputedColliderShape::TriMesh(_)))
+        ));
+        assert!(matches!(
+            physics[&6].collider,
+            Some(NodeCollider::Mesh(0, ComputedColliderShape::ConvexHull))
+        ));
+    }
+
+    #[test]
+    fn gltf_physics_is_imported() {
+        use bevy::asset::AssetPlugin;
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::gltf::{GltfLoaderSettings, GltfPlugin};
+        use bevy::scene::ScenePlugin;
+
+        let directory =
+            std::env::temp_dir().join(format!("bevy_rapier_gltf_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("physics.gltf"), DOCUMENT).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: directory.to_string_lossy().into_owned(),
+                ..Default::default()
+            },
+            ScenePlugin,
+            GltfPlugin::default(),
+        ))
+        .init_asset::<Mesh>()
+        .init_resource::<MeshColliderCache>()
+        .add_systems(Update, crate::plugin::systems::poll_async_collider_tasks);
+        app.finish();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let gltf: Handle<Gltf> =
+            asset_server.load_with_settings("physics.gltf", |settings: &mut GltfLoaderSettings| {
+                settings.include_source = true;
+            });
+        let missing: Handle<Gltf> = asset_server.load("missing.gltf");
+        let import_errors = |app: &mut App| {
+            let (gltf, missing) = (gltf.clone(), missing.clone());
+            app.world_mut()
+                .run_system_once(move |gltf_physics: GltfPhysicsAssets| {
+                    (
+                        gltf_physics.import_error(&gltf),
+                        gltf_physics.import_error(&missing),
+                    )
+                })
+                .unwrap()
+        };
+        let gltf_id = gltf.id();
+        for _ in 0..1000 {
+            app.update();
+            if asset_server.is_loaded(gltf_id) && import_errors(&mut app).1.is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        std::fs::remove_dir_all(&directory).unwrap();
+        let (loaded_error, missing_error) = import_errors(&mut app);
+        assert_eq!(loaded_error, None);
+        assert!(
+            missing_error.is_some(),
+            "The missing glTF should fail to load"
+        );
+
+        let world = app.world_mut();
+        let ground = world.spawn(Name::new("Ground")).id();
+        let ball_shape = world.spawn(Name::new("BallShape")).id();
+        let ball = world.spawn(Name::new("Ball")).add_child(ball_shape).id();
+        let hinge_frame = world.spawn(Name::new("HingeFrame")).id();
+        let hinge = world.spawn(Name::new("Hinge")).add_child(hinge_frame).id();
+        let rock = world.spawn(Name::new("Rock")).id();
+        let boulder = world.spawn(Name::new("Boulder")).id();
+        let scene = world
+            .spawn_empty()
+            .add_children(&[ground, ball, hinge, rock, boulder])
+            .id();
+        world
+            .run_system_once(
+                move |mut commands: Commands,
+                      gltf_physics: GltfPhysicsAssets,
+                      children: Query<&Children>,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      mut cache: ResMut<MeshColliderCache>| {
+                    assert!(gltf_physics.is_loaded(&gltf));
+                    gltf_physics.import(
+                        &mut commands,
+                        &gltf,
+                        scene,
+                        &children,
+                        &mut meshes,
+                        &mut cache,
+                    );
+                },
+            )
+            .unwrap();
+
+        let world = app.world();
+        assert!(world.get::<Collider>(ground).unwrap().as_cuboid().is_some());
+        assert_eq!(world.get::<Friction>(ground).unwrap().coefficient, 0.6);
+        assert!(world.get::<RigidBody>(ground).is_none());
+        assert_eq!(world.get::<RigidBody>(ball), Some(&RigidBody::Dynamic));
+        assert_eq!(world.get::<Velocity>(ball).unwrap().linvel, Vect::X);
+        assert!(world.get::<Sensor>(ball_shape).is_some());
+        assert!(world
+            .get::<Collider>(ball_shape)
+            .unwrap()
+            .as_ball()
+            .is_some());
+        assert_eq!(
+            world.get::<RigidBody>(hinge),
+            Some(&RigidBody::KinematicPositionBased)
+        );
+        assert_eq!(world.get::<ImpulseJoint>(hinge_frame).unwrap().parent, ball);
+
+        // The colliders of the meshes are computed asynchronously, from both of their primitives.
+        for _ in 0..1000 {
+            app.update();
+            if app.world().get::<AsyncColliderTask>(rock).is_none()
+                && app.world().get::<AsyncColliderTask>(boulder).is_none()
+            {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        let world = app.world();
+        let rock = world.get::<Collider>(rock).unwrap();
+        assert_eq!(rock.as_trimesh().unwrap().num_triangles(), 2);
+        let boulder = world.get::<Collider>(boulder).unwrap();
+        assert_eq!(boulder.as_convex_polyhedron().unwrap().points().len(), 4);
+    }
+}
@@ -0,0 +1,162 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::tasks::{futures::check_ready, AsyncComputeTaskPool, Task};
+
+use crate::geometry::{Collider, ComputedColliderShape, FillMode, VHACDParameters};
+
+#[cfg(doc)]
+use crate::geometry::{AsyncCollider, AsyncSceneCollider};
+
+/// The parameters of a [`ComputedColliderShape`], in a hashable form.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum ComputedShapeKey {
+    TriMesh(u16),
+    ConvexHull,
+    ConvexDecomposition {
+        // The bits of the concavity, alpha and beta parameters.
+        real_bits: [u64; 3],
+        resolution: u32,
+        plane_downsampling: u32,
+        convex_hull_downsampling: u32,
+        // The fill mode as `(flood_fill, detect_cavities, detect_self_intersections)`.
+        fill_mode: (bool, bool, bool),
+        convex_hull_approximation: bool,
+    },
+    #[cfg(feature = "dim2")]
+    Polyline,
+    // The fitted primitives with the bits of their margin.
+    Aabb(u64),
+    Obb(u64),
+    BoundingSphere(u64),
+    Capsule(u64),
+    #[cfg(feature = "dim3")]
+    Cylinder(u64),
+}
+
+impl ComputedShapeKey {
+    fn new(shape: &ComputedColliderShape) -> Self {
+        match shape {
+            ComputedColliderShape::TriMesh(flags) => Self::TriMesh(flags.bits()),
+            ComputedColliderShape::ConvexHull => Self::ConvexHull,
+            ComputedColliderShape::ConvexDecomposition(params) => {
+                Self::convex_decomposition(params)
+            }
+            #[cfg(feature = "dim2")]
+            ComputedColliderShape::Polyline => Self::Polyline,
+            ComputedColliderShape::Aabb { margin } => Self::Aabb(margin.to_bits().into()),
+            ComputedColliderShape::Obb { margin } => Self::Obb(margin.to_bits().into()),
+            ComputedColliderShape::BoundingSphere { margin } => {
+                Self::BoundingSphere(margin.to_bits().into())
+            }
+            ComputedColliderShape::Capsule { margin } => Self::Capsule(margin.to_bits().into()),
+            #[cfg(feature = "dim3")]
+            ComputedColliderShape::Cylinder { margin } => Self::Cylinder(margin.to_bits().into()),
+        }
+    }
+
+    fn convex_decomposition(params: &VHACDParameters) -> Self {
+        let fill_mode = match params.fill_mode {
+            FillMode::SurfaceOnly => (false, false, false),
+            #[cfg(feature = "dim2")]
+            FillMode::FloodFill {
+                detect_cavities,
+                detect_self_intersections,
+            } => (true, detect_cavities, detect_self_intersections),
+            #[cfg(feature = "dim3")]
+            FillMode::FloodFill { detect_cavities } => (true, detect_cavities, false),
+        };
+        Self::ConvexDecomposition {
+            real_bits: [
+                params.concavity.to_bits().into(),
+                params.alpha.to_bits().into(),
+                params.beta.to_bits().into(),
+            ],
+            resolution: params.resolution,
+            plane_downsampling: params.plane_downsampling,
+            convex_hull_downsampling: params.convex_hull_downsampling,
+            fill_mode,
+            convex_hull_approximation: params.convex_hull_approximation,
+        }
+    }
+}
+
+type CacheKey = (AssetId<Mesh>, ComputedShapeKey);
+
+/// A cache of the colliders computed from meshes for [`AsyncCollider`] and [`AsyncSceneCollider`]
+/// components.
+///
+/// Colliders are cached by mesh asset and [`ComputedColliderShape`], so the entities referencing
+/// the same mesh with the same shape parameters share the same [`SharedShape`](rapier::prelude::SharedShape)
+/// instead of computing their own. The colliders computed from a mesh are evicted when the mesh
+/// is modified or removed.
+#[derive(Resource, Default)]
+pub struct MeshColliderCache {
+    /// The computed colliders, or `None` if they couldn't be computed from their mesh.
+    colliders: HashMap<CacheKey, Option<Collider>>,
+    tasks: HashMap<CacheKey, Task<Option<Collider>>>,
+}
+
+impl MeshColliderCache {
+    /// The collider computed from the given mesh and shape parameters.
+    ///
+    /// Returns `None` if the collider isn't computed yet, and `Some(None)` if it couldn't be
+    /// computed from the mesh.
+    pub fn get(
+        &self,
+        mesh: AssetId<Mesh>,
+        shape: &ComputedColliderShape,
+    ) -> Option<Option<&Collider>> {
+        self.colliders
+            .get(&(mesh, ComputedShapeKey::new(shape)))
+            .map(Option::as_ref)
+    }
+
+    /// Spawns a task on the [`AsyncComputeTaskPool`] computing the collider of the given mesh,
+    /// unless it is already cached or being computed.
+    pub fn request(&mut self, id: AssetId<Mesh>, mesh: &Mesh, shape: &ComputedColliderShape) {
+        let key = (id, ComputedShapeKey::new(shape));
+        if self.colliders.contains_key(&key) || self.tasks.contains_key(&key) {
+            return;
+        }
+
+        let mesh = mesh.clone();
+        let shape = shape.clone();
+        let task = AsyncComputeTaskPool::get()
+            .spawn(async move { Collider::from_bevy_mesh(&mesh, &shape) });
+        self.tasks.insert(key, task);
+    }
+
+    /// Moves the colliders computed by the finished tasks into the cache.
+    pub(crate) fn poll_tasks(&mut self) {
+        let colliders = &mut self.colliders;
+        self.tasks.retain(|key, task| match check_ready(task) {
+            Some(collider) => {
+                colliders.insert(key.clone(), collider);
+                false
+            }
+            None => true,
+        });
+    }
+
+    /// Evicts the colliders computed from the given mesh, and cancels their computation.
+    pub fn invalidate(&mut self, mesh: AssetId<Mesh>) {
+        self.colliders.retain(|(id, _), _| *id != mesh);
+        self.tasks.retain(|(id, _), _| *id != mesh);
+    }
+
+    /// The number of colliders in the cache.
+    pub fn len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    /// Whether the cache contains no collider.
+    pub fn is_empty(&self) -> bool {
+        self.colliders.is_empty()
+    }
+
+    /// Evicts all the colliders of the cache, and cancels the pending computations.
+    pub fn clear(&mut self) {
+        self.colliders.clear();
+        self.tasks.clear();
+    }
+}
@@ -1,19 +1,45 @@
 pub use self::collider::*;
+#[cfg(feature = "collider-asset")]
+pub use self::collider_asset::*;
+pub use self::collider_shape::ColliderShape;
+#[cfg(all(feature = "dim3", feature = "gltf"))]
+pub use self::gltf_extras::{GltfColliderExtras, GltfColliderShape, GltfRigidBody};
+#[cfg(all(feature = "dim3", feature = "gltf"))]
+pub use self::gltf_physics::GltfPhysicsAssets;
+#[cfg(feature = "async-collider")]
+pub use self::mesh_collider_cache::MeshColliderCache;
 pub use self::shape_views::ColliderView;
+pub use self::trigger_zone::{
+    FilteredTriggerZone, RapierTriggerZoneFilterPlugin, TriggerZone, TriggerZoneEnter,
+    TriggerZoneExit, TriggerZoneExitReason, TriggerZoneFilter, TriggerZoneOccupant,
+    TriggerZoneStay, TRIGGER_ZONE_COLLISION_TYPES,
+};
 pub use rapier::geometry::SolverFlags;
 pub use rapier::parry::query::{ShapeCastOptions, ShapeCastStatus};
 pub use rapier::parry::shape::TriMeshFlags;
 pub use rapier::parry::transformation::{vhacd::VHACDParameters, voxelization::FillMode};
 
 use crate::math::{Real, Vect};
+use crate::reflect::ShapeCastStatusWrapper;
+use bevy::reflect::Reflect;
 use rapier::prelude::FeatureId;
 
 mod collider;
+#[cfg(feature = "collider-asset")]
+mod collider_asset;
 mod collider_impl;
+mod collider_shape;
+#[cfg(all(feature = "dim3", feature = "gltf"))]
+pub(crate) mod gltf_extras;
+#[cfg(all(feature = "dim3", feature = "gltf"))]
+pub(crate) mod gltf_physics;
+#[cfg(feature = "async-collider")]
+mod mesh_collider_cache;
 /// Wrappers around Rapier shapes to access their properties.
 pub mod shape_views;
 #[cfg(feature = "to-bevy-mesh")]
 pub mod to_bevy_mesh;
+mod trigger_zone;
 
 /// Result of the projection of a point on a shape.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -77,7 +103,8 @@ impl RayIntersection {
 }
 
 /// The result of a shape cast.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
 pub struct ShapeCastHit {
     /// The time at which the objects touch.
     pub time_of_impact: Real,
@@ -87,11 +114,13 @@ pub struct ShapeCastHit {
     /// [`ShapeCastOptions::compute_impact_geometry_on_penetration`] was `false`.
     pub details: Option<ShapeCastHitDetails>,
     /// The way the time-of-impact computation algorithm terminated.
+    #[reflect(remote = ShapeCastStatusWrapper)]
     pub status: ShapeCastStatus,
 }
 
 /// In depth information about a shape-cast hit.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
 pub struct ShapeCastHitDetails {
     /// The local-space closest point on the first shape at the time of impact.
     pub witness1: Vect,
@@ -0,0 +1,348 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::{
+    component::HookContext, intern::Interned, schedule::ScheduleLabel, world::DeferredWorld,
+};
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+
+use crate::geometry::{ActiveCollisionTypes, ActiveEvents, Sensor};
+use crate::plugin::{systems, PhysicsSet};
+
+#[cfg(doc)]
+use crate::prelude::{CollidingEntities, CollisionEvent};
+
+/// A [`Sensor`] collider tracking the colliders inside of it, and triggering a
+/// [`TriggerZoneEnter`], [`TriggerZoneStay`] and [`TriggerZoneExit`] event on its entity when they
+/// enter it, stay in it and exit it.
+///
+/// Unlike [`CollidingEntities`], the occupants of the zone are kept in the order they entered it,
+/// along with the simulation time they spent in it, and can be filtered by component with a
+/// [`TriggerZoneFilter`]. An occupant exits the zone when its collider or its rigid-body is
+/// disabled or despawned.
+///
+/// The zone is updated from the [`CollisionEvent`]s of its collider, so it requires the
+/// [`ActiveEvents::COLLISION_EVENTS`] flag, added by default. The collisions with kinematic
+/// rigid-bodies, such as the ones of character controllers, are also enabled by default with
+/// [`TRIGGER_ZONE_COLLISION_TYPES`].
+#[derive(Clone, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+#[require(
+    Sensor,
+    ActiveEvents = ActiveEvents::COLLISION_EVENTS,
+    ActiveCollisionTypes = TRIGGER_ZONE_COLLISION_TYPES
+)]
+pub struct TriggerZone {
+    pub(crate) occupants: Vec<TriggerZoneOccupant>,
+}
+
+/// The [`ActiveCollisionTypes`] added with a [`TriggerZone`]: the default ones, along with the
+/// collisions between kinematic rigid-bodies and fixed or kinematic ones, so that kinematic
+/// colliders can enter the zones which aren't attached to a dynamic rigid-body.
+pub const TRIGGER_ZONE_COLLISION_TYPES: ActiveCollisionTypes =
+    ActiveCollisionTypes::DYNAMIC_DYNAMIC
+        .union(ActiveCollisionTypes::DYNAMIC_KINEMATIC)
+        .union(ActiveCollisionTypes::DYNAMIC_STATIC)
+        .union(ActiveCollisionTypes::KINEMATIC_KINEMATIC)
+        .union(ActiveCollisionTypes::KINEMATIC_STATIC);
+
+/// A collider inside of a [`TriggerZone`].
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+pub struct TriggerZoneOccupant {
+    /// The entity of the collider.
+    pub entity: Entity,
+    /// The simulation time spent in the zone, in seconds.
+    pub time: f32,
+}
+
+/// Restricts the occupants of the [`TriggerZone`] of its entity to the colliders whose entity, or
+/// the entity of their rigid-body, has a component of type `T`.
+///
+/// The filter is checked again during each physics update: an occupant which no longer has the
+/// component exits the zone with [`TriggerZoneExitReason::Filtered`]. A collider which gets the
+/// component while already intersecting the zone only enters it once it starts intersecting it
+/// again.
+///
+/// The filtered zones are updated by the [`RapierTriggerZoneFilterPlugin`] of `T`, which must be
+/// added to the app. A zone can only have one filter: once it is removed, the zone isn't filtered
+/// anymore.
+#[derive(Component, Reflect)]
+#[component(on_remove = remove_filtered_trigger_zone)]
+#[reflect(Component, Default)]
+#[require(TriggerZone, FilteredTriggerZone)]
+pub struct TriggerZoneFilter<T: Component + TypePath>(#[reflect(ignore)] PhantomData<T>);
+
+impl<T: Component + TypePath> Default for TriggerZoneFilter<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+fn remove_filtered_trigger_zone(mut world: DeferredWorld, context: HookContext) {
+    world
+        .commands()
+        .entity(context.entity)
+        .try_remove::<FilteredTriggerZone>();
+}
+
+/// Marker added along with a [`TriggerZoneFilter`], so that its zone is only updated by the
+/// [`RapierTriggerZoneFilterPlugin`] of the filter. It is removed along with the filter.
+#[derive(Copy, Clone, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct FilteredTriggerZone;
+
+/// A plugin updating the [`TriggerZone`]s with a [`TriggerZoneFilter`] of `T`.
+///
+/// This must be added after [`RapierPhysicsPlugin`](crate::plugin::RapierPhysicsPlugin), with the same schedule.
+pub struct RapierTriggerZoneFilterPlugin<T> {
+    schedule: Interned<dyn ScheduleLabel>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> RapierTriggerZoneFilterPlugin<T> {
+    /// Updates the filtered zones in the provided schedule rather than `PostUpdate`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl<T> Default for RapierTriggerZoneFilterPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Component + TypePath> Plugin for RapierTriggerZoneFilterPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TriggerZoneFilter<T>>().add_systems(
+            self.schedule,
+            systems::update_filtered_trigger_zones::<T>.in_set(PhysicsSet::Writeback),
+        );
+    }
+}
+
+impl TriggerZone {
+    /// The colliders inside of the zone, in the order they entered it.
+    pub fn occupants(&self) -> &[TriggerZoneOccupant] {
+        &self.occupants
+    }
+
+    /// Returns `true` if the given collider entity is inside of the zone.
+    #[must_use]
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.occupants
+            .iter()
+            .any(|occupant| occupant.entity == entity)
+    }
+
+    /// Returns the number of colliders inside of the zone.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.occupants.len()
+    }
+
+    /// Returns `true` if there is no collider inside of the zone.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.occupants.is_empty()
+    }
+}
+
+/// Triggered on the entity of a [`TriggerZone`] when a collider enters it.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TriggerZoneEnter {
+    /// The entity of the collider entering the zone.
+    pub occupant: Entity,
+}
+
+/// Triggered on the entity of a [`TriggerZone`] for each of its occupants, during each physics
+/// update following the one they entered it.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct TriggerZoneStay {
+    /// The entity of the collider inside of the zone.
+    pub occupant: Entity,
+    /// The simulation time spent in the zone, in seconds.
+    pub time: f32,
+}
+
+/// Triggered on the entity of a [`TriggerZone`] when a collider exits it.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct TriggerZoneExit {
+    /// The entity of the collider exiting the zone.
+    pub occupant: Entity,
+    /// The simulation time spent in the zone, in seconds.
+    pub time: f32,
+    /// Why the collider exited the zone.
+    pub reason: TriggerZoneExitReason,
+}
+
+/// Why a collider exited a [`TriggerZone`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum TriggerZoneExitReason {
+    /// The collider stopped intersecting the zone.
+    Left,
+    /// The collider, or its rigid-body, was disabled.
+    Disabled,
+    /// The collider was despawned, or its collider was removed.
+    Despawned,
+    /// The collider, and its rigid-body, no longer have the component required by the
+    /// [`TriggerZoneFilter`] of the zone.
+    Filtered,
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Component, TypePath)]
+    struct Player;
+
+    #[test]
+    fn trigger_zone_tracks_occupants() {
+        #[derive(Resource, Default)]
+        struct ZoneEvents(Vec<String>);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierTriggerZoneFilterPlugin::<Player>::default(),
+        ))
+        .init_resource::<ZoneEvents>()
+        // The time spent in the zone is the simulated one, not the frame time.
+        .insert_resource(TimestepMode::Fixed {
+            dt: 0.25,
+            substeps: 1,
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1.0),
+        ));
+        app.finish();
+
+        let world = app.world_mut();
+        #[cfg(feature = "dim2")]
+        let zone_collider = Collider::cuboid(5.0, 5.0);
+        #[cfg(feature = "dim3")]
+        let zone_collider = Collider::cuboid(5.0, 5.0, 5.0);
+        let zone = world
+            .spawn((
+                Transform::default(),
+                zone_collider,
+                TriggerZoneFilter::<Player>::default(),
+            ))
+            .observe(
+                |trigger: Trigger<TriggerZoneEnter>, mut events: ResMut<ZoneEvents>| {
+                    events.0.push(format!("enter {}", trigger.occupant));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TriggerZoneStay>, mut events: ResMut<ZoneEvents>| {
+                    events
+                        .0
+                        .push(format!("stay {} {}", trigger.occupant, trigger.time));
+                },
+            )
+            .observe(
+                |trigger: Trigger<TriggerZoneExit>, mut events: ResMut<ZoneEvents>| {
+                    events.0.push(format!(
+                        "exit {} {} {:?}",
+                        trigger.occupant, trigger.time, trigger.reason
+                    ));
+                },
+            )
+            .id();
+        let body = world
+            .spawn((
+                Transform::from_xyz(1.0, 0.0, 0.0),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Player,
+            ))
+            .id();
+        let player = world.spawn((Collider::ball(0.5), ChildOf(body))).id();
+        world.spawn((
+            Transform::from_xyz(-1.0, 0.0, 0.0),
+            RigidBody::Dynamic,
+            GravityScale(0.0),
+            Collider::ball(0.5),
+        ));
+
+        let update = |app: &mut App| {
+            app.update();
+            std::mem::take(&mut app.world_mut().resource_mut::<ZoneEvents>().0)
+        };
+        assert_eq!(update(&mut app), vec![format!("enter {player}")]);
+        assert_eq!(update(&mut app), vec![format!("stay {player} 0.25")]);
+        assert_eq!(update(&mut app), vec![format!("stay {player} 0.5")]);
+        let zone_component = app.world().get::<TriggerZone>(zone).unwrap();
+        assert_eq!(zone_component.len(), 1);
+        assert!(zone_component.contains(player));
+
+        app.world_mut().entity_mut(body).insert(RigidBodyDisabled);
+        assert_eq!(
+            update(&mut app),
+            vec![format!("exit {player} 0.5 Disabled")]
+        );
+        app.world_mut()
+            .entity_mut(body)
+            .remove::<RigidBodyDisabled>();
+        assert_eq!(update(&mut app), vec![format!("enter {player}")]);
+
+        app.world_mut().entity_mut(player).despawn();
+        assert_eq!(update(&mut app), vec![format!("exit {player} 0 Despawned")]);
+        assert!(app.world().get::<TriggerZone>(zone).unwrap().is_empty());
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Collider::ball(0.5),
+                Player,
+            ))
+            .id();
+        assert_eq!(update(&mut app), vec![format!("enter {player}")]);
+        app.world_mut().entity_mut(player).remove::<Player>();
+        assert_eq!(update(&mut app), vec![format!("exit {player} 0 Filtered")]);
+        // The filter isn't checked again until the collider starts intersecting the zone again.
+        app.world_mut().entity_mut(player).insert(Player);
+        assert_eq!(update(&mut app), Vec::<String>::new());
+
+        // Once the filter is removed, the zone is updated like the unfiltered ones, and kinematic
+        // colliders enter it too.
+        app.world_mut()
+            .entity_mut(zone)
+            .remove::<TriggerZoneFilter<Player>>();
+        assert!(app.world().get::<FilteredTriggerZone>(zone).is_none());
+        let kinematic = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 2.0, 0.0),
+                RigidBody::KinematicPositionBased,
+                Collider::ball(0.5),
+            ))
+            .id();
+        assert_eq!(update(&mut app), vec![format!("enter {kinematic}")]);
+    }
+
+    #[test]
+    fn trigger_zone_filter_is_reflected() {
+        let mut app = App::new();
+        app.add_plugins(RapierTriggerZoneFilterPlugin::<Player>::default());
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get_with_type_path(TriggerZoneFilter::<Player>::type_path())
+            .expect("The filter should be registered, to be saved in scenes");
+        assert!(registration.data::<ReflectComponent>().is_some());
+        assert!(registration.data::<ReflectDefault>().is_some());
+    }
+}
@@ -69,6 +69,13 @@ pub mod picking_backend;
 
 /// Components related to character control.
 pub mod control;
+/// Headless recording of the debug-render lines, and their export to SVG.
+#[cfg(any(
+    feature = "rapier-debug-render",
+    feature = "debug-render-3d",
+    feature = "debug-render-2d"
+))]
+pub mod debug_lines;
 /// The debug-renderer.
 #[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
 pub mod render;
@@ -78,6 +85,12 @@ pub mod utils;
 /// Groups the most often used types.
 pub mod prelude {
     pub use crate::control::*;
+    #[cfg(any(
+        feature = "rapier-debug-render",
+        feature = "debug-render-3d",
+        feature = "debug-render-2d"
+    ))]
+    pub use crate::debug_lines::*;
     pub use crate::dynamics::*;
     pub use crate::geometry::*;
     pub use crate::math::*;
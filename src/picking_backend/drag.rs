@@ -0,0 +1,333 @@
+//! Grabbing and moving dynamic rigid-bodies with the pointer.
+//!
+//! When a pointer starts dragging a collider, the [`RapierDragPlugin`] spawns a kinematic
+//! [`RapierDragAnchor`] at the picked point, attached to the rigid-body of the collider with a
+//! spring joint. The anchor follows the pointer until the drag ends, then it is despawned.
+
+use bevy::app::prelude::*;
+use bevy::ecs::prelude::*;
+#[cfg(feature = "dim3")]
+use bevy::math::{primitives::InfinitePlane3d, Vec3};
+use bevy::picking::{
+    backend::ray::{RayId, RayMap},
+    events::{Cancel, DragEnd, DragStart, Pointer},
+    pointer::{PointerButton, PointerId},
+    PickSet,
+};
+use bevy::reflect::prelude::*;
+use bevy::transform::prelude::*;
+
+use super::RapierPickingPlugin;
+use crate::dynamics::{ImpulseJoint, MotorModel, RigidBody, SpringJointBuilder, Velocity};
+use crate::math::{Real, Vect};
+use crate::plugin::context::RapierContextEntityLink;
+
+/// Runtime settings for the [`RapierDragPlugin`].
+#[derive(Resource, Reflect, Clone, Copy, Debug, PartialEq)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct RapierDragSettings {
+    /// The pointer button dragging the rigid-bodies. [`PointerButton::Primary`] by default.
+    pub button: PointerButton,
+    /// The stiffness of the spring pulling the dragged point towards the pointer.
+    ///
+    /// The spring is acceleration-based, so the dragged rigid-bodies behave the same regardless
+    /// of their mass.
+    pub stiffness: Real,
+    /// The damping of the spring pulling the dragged point towards the pointer.
+    pub damping: Real,
+    /// Whether the dragged rigid-bodies keep their velocity when released, so they can be thrown.
+    /// If `false`, their [`Velocity`] is reset on release. `true` by default.
+    pub throw: bool,
+}
+
+impl Default for RapierDragSettings {
+    fn default() -> Self {
+        Self {
+            button: PointerButton::Primary,
+            stiffness: 100.0,
+            // Critical damping of the default stiffness.
+            damping: 20.0,
+            throw: true,
+        }
+    }
+}
+
+/// A kinematic rigid-body following a pointer, spawned by the [`RapierDragPlugin`] and attached to
+/// the dragged rigid-body with a spring [`ImpulseJoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct RapierDragAnchor {
+    /// The dragged rigid-body.
+    pub body: Entity,
+    /// The pointer dragging the rigid-body.
+    pub pointer: PointerId,
+    /// The pointer button dragging the rigid-body.
+    pub button: PointerButton,
+    /// The camera the rigid-body was picked from.
+    pub camera: Entity,
+    /// The normal of the plane the anchor moves on, facing the camera and containing the picked
+    /// point.
+    #[cfg(feature = "dim3")]
+    pub plane_normal: Vec3,
+}
+
+/// Adds the dragging of dynamic rigid-bodies with the pointer to your app.
+///
+/// The picked rigid-bodies must be [`RigidBody::Dynamic`]. The picking hits are usually provided
+/// by the [`RapierPickingPlugin`], which is added if it isn't already. See
+/// [`RapierDragSettings`] to configure the dragging.
+#[derive(Clone, Default)]
+pub struct RapierDragPlugin;
+
+impl Plugin for RapierDragPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<(RapierDragSettings, RapierDragAnchor)>()
+            .init_resource::<RapierDragSettings>()
+            .add_systems(
+                PreUpdate,
+                (start_drags, move_drag_anchors, end_drags)
+                    .chain()
+                    .after(PickSet::Last),
+            );
+        if !app.is_plugin_added::<RapierPickingPlugin>() {
+            app.add_plugins(RapierPickingPlugin);
+        }
+    }
+}
+
+/// System spawning a [`RapierDragAnchor`] for each [`DragStart`] event on a dynamic rigid-body or
+/// on one of its colliders.
+pub fn start_drags(
+    mut commands: Commands,
+    settings: Res<RapierDragSettings>,
+    mut drag_starts: EventReader<Pointer<DragStart>>,
+    bodies: Query<(&RigidBody, Option<&RapierContextEntityLink>)>,
+    parents: Query<&ChildOf>,
+    transforms: Query<&GlobalTransform>,
+) {
+    for drag_start in drag_starts.read() {
+        if drag_start.button != settings.button {
+            continue;
+        }
+        let Some(position) = drag_start.hit.position else {
+            continue;
+        };
+
+        // The picked entity is a collider, either attached to its rigid-body or one of its
+        // descendants.
+        let mut entity = drag_start.target;
+        let (body, rigid_body, context_link) = loop {
+            if let Ok((rigid_body, context_link)) = bodies.get(entity) {
+                break (entity, rigid_body, context_link);
+            }
+            match parents.get(entity) {
+                Ok(child_of) => entity = child_of.parent(),
+                Err(_) => break (entity, &RigidBody::Fixed, None),
+            }
+        };
+        if *rigid_body != RigidBody::Dynamic {
+            continue;
+        }
+        let Ok(body_transform) = transforms.get(body) else {
+            continue;
+        };
+
+        let (_, rotation, translation) = body_transform.to_scale_rotation_translation();
+        let local_anchor = rotation.inverse() * (position - translation);
+        #[cfg(feature = "dim2")]
+        let local_anchor = local_anchor.truncate();
+        let joint = SpringJointBuilder::new(0.0, settings.stiffness, settings.damping)
+            .spring_model(MotorModel::AccelerationBased)
+            .local_anchor1(local_anchor)
+            .local_anchor2(Vect::ZERO);
+
+        let mut anchor = commands.spawn((
+            Transform::from_translation(position),
+            RigidBody::KinematicPositionBased,
+            ImpulseJoint::new(body, joint),
+            RapierDragAnchor {
+                body,
+                pointer: drag_start.pointer_id,
+                button: drag_start.button,
+                camera: drag_start.hit.camera,
+                #[cfg(feature = "dim3")]
+                plane_normal: transforms
+                    .get(drag_start.hit.camera)
+                    .map_or(Vec3::Z, |camera| camera.back().into()),
+            },
+        ));
+        if let Some(context_link) = context_link {
+            anchor.insert(*context_link);
+        }
+    }
+}
+
+/// System moving the [`RapierDragAnchor`]s under their pointer, and despawning the ones whose
+/// rigid-body no longer exists.
+pub fn move_drag_anchors(
+    mut commands: Commands,
+    ray_map: Res<RayMap>,
+    mut anchors: Query<(Entity, &RapierDragAnchor, &mut Transform)>,
+    bodies: Query<(), With<RigidBody>>,
+) {
+    for (entity, anchor, mut transform) in anchors.iter_mut() {
+        if !bodies.contains(anchor.body) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // The pointer may temporarily be outside of the viewport of the camera.
+        let Some(ray) = ray_map.map.get(&RayId::new(anchor.camera, anchor.pointer)) else {
+            continue;
+        };
+
+        #[cfg(feature = "dim2")]
+        {
+            transform.translation.x = ray.origin.x;
+            transform.translation.y = ray.origin.y;
+        }
+        #[cfg(feature = "dim3")]
+        if let Some(distance) = ray.intersect_plane(
+            transform.translation,
+            InfinitePlane3d::new(anchor.plane_normal),
+        ) {
+            transform.translation = ray.get_point(distance);
+        }
+    }
+}
+
+/// System despawning the [`RapierDragAnchor`]s of the pointers whose drag ended or which were
+/// canceled.
+pub fn end_drags(
+    mut commands: Commands,
+    settings: Res<RapierDragSettings>,
+    mut drag_ends: EventReader<Pointer<DragEnd>>,
+    mut cancels: EventReader<Pointer<Cancel>>,
+    anchors: Query<(Entity, &RapierDragAnchor)>,
+    mut velocities: Query<&mut Velocity>,
+) {
+    let released = drag_ends
+        .read()
+        .map(|drag_end| (drag_end.pointer_id, Some(drag_end.button)))
+        .chain(cancels.read().map(|cancel| (cancel.pointer_id, None)));
+    for (pointer, button) in released {
+        for (entity, anchor) in anchors.iter() {
+            if anchor.pointer != pointer || button.is_some_and(|button| button != anchor.button) {
+                continue;
+            }
+            commands.entity(entity).despawn();
+            if !settings.throw {
+                match velocities.get_mut(anchor.body) {
+                    Ok(mut velocity) => *velocity = Velocity::zero(),
+                    Err(_) => {
+                        if let Ok(mut body) = commands.get_entity(anchor.body) {
+                            body.insert(Velocity::zero());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::math::{Dir3, Ray3d, Vec3};
+    use bevy::picking::backend::HitData;
+    use bevy::picking::pointer::Location;
+    use bevy::prelude::TransformPlugin;
+    use bevy::render::camera::{ManualTextureViewHandle, NormalizedRenderTarget};
+    use bevy::time::TimePlugin;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn dragged_bodies_follow_the_pointer() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        })
+        .add_event::<Pointer<DragStart>>()
+        .add_event::<Pointer<DragEnd>>()
+        .add_event::<Pointer<Cancel>>()
+        .init_resource::<RayMap>()
+        .insert_resource(RapierDragSettings {
+            throw: false,
+            ..Default::default()
+        })
+        .add_systems(
+            PreUpdate,
+            (start_drags, move_drag_anchors, end_drags).chain(),
+        );
+        app.finish();
+
+        let world = app.world_mut();
+        let camera = world.spawn(Transform::from_xyz(0.0, 0.0, 10.0)).id();
+        let body = world
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+        app.update();
+
+        let location = Location {
+            target: NormalizedRenderTarget::TextureView(ManualTextureViewHandle(0)),
+            position: Default::default(),
+        };
+        let set_ray = |app: &mut App, x: f32| {
+            app.world_mut().resource_mut::<RayMap>().map.insert(
+                RayId::new(camera, PointerId::Mouse),
+                Ray3d::new(Vec3::new(x, 0.0, 10.0), Dir3::NEG_Z),
+            );
+        };
+        set_ray(&mut app, 0.0);
+        app.world_mut().send_event(Pointer::new(
+            PointerId::Mouse,
+            location.clone(),
+            body,
+            DragStart {
+                button: PointerButton::Primary,
+                hit: HitData::new(camera, 10.0, Some(Vec3::ZERO), None),
+            },
+        ));
+        app.update();
+        let mut anchors = app.world_mut().query::<&RapierDragAnchor>();
+        assert_eq!(anchors.single(app.world()).unwrap().body, body);
+
+        set_ray(&mut app, 2.0);
+        for _ in 0..120 {
+            app.update();
+        }
+        let position = app.world().get::<Transform>(body).unwrap().translation;
+        assert!(
+            (position.x - 2.0).abs() < 0.2 && position.y.abs() < 0.2,
+            "The dragged body should follow the pointer, at {position}"
+        );
+
+        app.world_mut().send_event(Pointer::new(
+            PointerId::Mouse,
+            location,
+            body,
+            DragEnd {
+                button: PointerButton::Primary,
+                distance: Default::default(),
+            },
+        ));
+        app.update();
+        assert!(anchors.iter(app.world()).next().is_none());
+        assert_eq!(
+            *app.world().get::<Velocity>(body).unwrap(),
+            Velocity::zero(),
+            "The released body shouldn't be thrown"
+        );
+    }
+}
@@ -5,6 +5,14 @@
 //!
 //! To make rapier picking entirely opt-in, set [`RapierPickingSettings::require_markers`]
 //! to `true` and add a [`RapierPickable`] component to the desired camera and target entities.
+//!
+//! The scene queries can be configured with a [`RapierPickingFilter`], globally through
+//! [`RapierPickingSettings::filter`] or for a single camera by adding it to the camera entity.
+//! A camera with a [`RapierContextEntityLink`] only picks into the linked rapier context.
+//!
+//! In 2D, the depth of the hits is computed from the `z` translation of the [`GlobalTransform`]
+//! of the picked entities, or from their [`RapierPickingSortKey`], so that the topmost collider
+//! is picked first.
 
 use bevy::app::prelude::*;
 use bevy::ecs::prelude::*;
@@ -15,6 +23,17 @@ use bevy::picking::{
 use bevy::prelude::PickingPlugin;
 use bevy::reflect::prelude::*;
 use bevy::render::{prelude::*, view::RenderLayers};
+#[cfg(feature = "dim2")]
+use bevy::transform::prelude::GlobalTransform;
+
+use crate::geometry::CollisionGroups;
+use crate::math::Real;
+use crate::pipeline::QueryFilter;
+use crate::plugin::context::RapierContextEntityLink;
+
+pub use self::drag::*;
+
+mod drag;
 
 /// How a ray cast should handle [`Visibility`].
 #[derive(Clone, Copy, Reflect)]
@@ -40,6 +59,16 @@ pub struct RapierPickingSettings {
     ///
     /// Defaults to [`RapierCastVisibility::Visible`], only performing picking against entities with [`InheritedVisibility`] set to `true`.
     pub ray_cast_visibility: RapierCastVisibility,
+
+    /// The radius of the ball tested against the colliders at the position of the pointers, or
+    /// `None` to only pick the colliders containing this position. `None` by default.
+    ///
+    /// A radius makes small colliders easier to pick, with touch inputs for example.
+    #[cfg(feature = "dim2")]
+    pub pick_radius: Option<Real>,
+
+    /// The filter of the scene queries of the cameras without their own [`RapierPickingFilter`].
+    pub filter: RapierPickingFilter,
 }
 
 impl Default for RapierPickingSettings {
@@ -47,10 +76,67 @@ impl Default for RapierPickingSettings {
         Self {
             require_markers: false,
             ray_cast_visibility: RapierCastVisibility::Visible,
+            #[cfg(feature = "dim2")]
+            pick_radius: None,
+            filter: RapierPickingFilter::default(),
         }
     }
 }
 
+/// The filter of the scene queries of the [`RapierPickingPlugin`].
+///
+/// Set globally by [`RapierPickingSettings::filter`], or for a single camera by adding this
+/// component to the camera entity.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct RapierPickingFilter {
+    /// The collision groups the picked colliders must interact with, or `None` to pick colliders
+    /// regardless of their groups.
+    pub groups: Option<CollisionGroups>,
+    /// Whether the [`Sensor`](crate::geometry::Sensor) colliders are ignored.
+    pub exclude_sensors: bool,
+    /// The maximum distance between the camera and the picked colliders, along the pointer ray.
+    pub max_distance: Real,
+    /// Whether the pointer ray hits the colliders it starts in at the ray origin. If `false`, it
+    /// hits their boundary instead.
+    #[cfg(feature = "dim3")]
+    pub solid: bool,
+}
+
+impl Default for RapierPickingFilter {
+    fn default() -> Self {
+        Self {
+            groups: None,
+            exclude_sensors: false,
+            max_distance: Real::MAX,
+            #[cfg(feature = "dim3")]
+            solid: true,
+        }
+    }
+}
+
+impl RapierPickingFilter {
+    fn query_filter<'a>(&self, predicate: &'a impl Fn(Entity) -> bool) -> QueryFilter<'a> {
+        let mut filter = QueryFilter::default().predicate(predicate);
+        if let Some(groups) = self.groups {
+            filter = filter.groups(groups);
+        }
+        if self.exclude_sensors {
+            filter = filter.exclude_sensors();
+        }
+        filter
+    }
+}
+
+/// A component overriding the `z` translation of the [`GlobalTransform`] of an entity, when
+/// computing the depth of its picking hits in 2D.
+///
+/// The entities with the greatest key are picked first.
+#[cfg(feature = "dim2")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct RapierPickingSortKey(pub f32);
+
 /// An optional component that marks cameras and target entities that should be used in the [`RapierPickingPlugin`].
 ///
 /// Only needed if [`RapierPickingSettings::require_markers`] is set to `true`, and ignored otherwise.
@@ -64,9 +150,11 @@ pub struct RapierPickingPlugin;
 
 impl Plugin for RapierPickingPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<(RapierPickable, RapierPickingSettings)>()
+        app.register_type::<(RapierPickable, RapierPickingSettings, RapierPickingFilter)>()
             .init_resource::<RapierPickingSettings>()
             .add_systems(PreUpdate, update_hits.in_set(PickSet::Backend));
+        #[cfg(feature = "dim2")]
+        app.register_type::<RapierPickingSortKey>();
         if !app.is_plugin_added::<PickingPlugin>() {
             app.add_plugins(PickingPlugin::default());
         }
@@ -74,30 +162,48 @@ impl Plugin for RapierPickingPlugin {
 }
 
 /// System which casts rays into the scene using [`RapierPickingSettings`] and sends [`PointerHits`] events.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn update_hits(
     backend_settings: Res<RapierPickingSettings>,
     ray_map: Res<RayMap>,
-    picking_cameras: Query<(&Camera, Option<&RapierPickable>, Option<&RenderLayers>)>,
+    picking_cameras: Query<(
+        &Camera,
+        Option<&RapierPickable>,
+        Option<&RenderLayers>,
+        Option<&RapierPickingFilter>,
+        Option<&RapierContextEntityLink>,
+    )>,
     marked_targets: Query<&RapierPickable>,
     culling_query: Query<(Option<&InheritedVisibility>, Option<&ViewVisibility>)>,
     layers: Query<&RenderLayers>,
     rapier_context: Query<(
+        Entity,
         &crate::prelude::RapierContextColliders,
         &crate::prelude::RapierRigidBodySet,
         &crate::prelude::RapierQueryPipeline,
     )>,
+    #[cfg(feature = "dim2")] sort_keys: Query<(
+        Option<&RapierPickingSortKey>,
+        Option<&GlobalTransform>,
+    )>,
     mut output: EventWriter<PointerHits>,
 ) {
     for (&ray_id, &ray) in ray_map.map.iter() {
-        let Ok((camera, cam_pickable, cam_layers)) = picking_cameras.get(ray_id.camera) else {
+        let Ok((camera, cam_pickable, cam_layers, cam_filter, cam_context)) =
+            picking_cameras.get(ray_id.camera)
+        else {
             continue;
         };
         if backend_settings.require_markers && cam_pickable.is_none() {
             continue;
         }
         let order = camera.order as f32;
-        for (colliders, bodies, query_pipeline) in rapier_context.iter() {
+        let picking_filter = cam_filter.unwrap_or(&backend_settings.filter);
+        for (context_entity, colliders, bodies, query_pipeline) in rapier_context.iter() {
+            if cam_context.is_some_and(|link| link.0 != context_entity) {
+                continue;
+            }
+
             let predicate = |entity| {
                 let marker_requirement =
                     !backend_settings.require_markers || marked_targets.get(entity).is_ok();
@@ -133,31 +239,55 @@ pub fn update_hits(
 
             let mut picks = Vec::new();
             #[cfg(feature = "dim2")]
-            query_pipeline.intersections_with_point(
-                colliders,
-                bodies,
-                bevy::math::Vec2::new(ray.origin.x, ray.origin.y),
-                crate::prelude::QueryFilter::default().predicate(&predicate),
-                |entity| {
+            {
+                let mut callback = |entity| {
+                    // The hit is at the position of the pointer, on the plane of the entity.
+                    let z = match sort_keys.get(entity) {
+                        Ok((Some(sort_key), _)) => sort_key.0,
+                        Ok((None, Some(transform))) => transform.translation().z,
+                        _ => 0.0,
+                    };
                     let hit_data = HitData {
                         camera: ray_id.camera,
-                        position: Some(bevy::math::Vec3::new(ray.origin.x, ray.origin.y, 0.0)),
+                        position: Some(bevy::math::Vec3::new(ray.origin.x, ray.origin.y, z)),
                         normal: None,
-                        depth: 0.0,
+                        depth: (z - ray.origin.z) * ray.direction.z,
                     };
-                    picks.push((entity, hit_data));
+                    if hit_data.depth <= picking_filter.max_distance {
+                        picks.push((entity, hit_data));
+                    }
                     true
-                },
-            );
+                };
+                let point = bevy::math::Vec2::new(ray.origin.x, ray.origin.y);
+                let filter = picking_filter.query_filter(&predicate);
+                match backend_settings.pick_radius {
+                    Some(radius) => query_pipeline.intersections_with_shape(
+                        colliders,
+                        bodies,
+                        point,
+                        0.0,
+                        &crate::prelude::Collider::ball(radius),
+                        filter,
+                        &mut callback,
+                    ),
+                    None => query_pipeline.intersections_with_point(
+                        colliders,
+                        bodies,
+                        point,
+                        filter,
+                        &mut callback,
+                    ),
+                }
+            }
             #[cfg(feature = "dim3")]
             query_pipeline.intersections_with_ray(
                 colliders,
                 bodies,
                 ray.origin,
                 ray.direction.into(),
-                f32::MAX,
-                true,
-                crate::prelude::QueryFilter::default().predicate(&predicate),
+                picking_filter.max_distance,
+                picking_filter.solid,
+                picking_filter.query_filter(&predicate),
                 |entity, intersection| {
                     let hit_data = HitData {
                         camera: ray_id.camera,
@@ -176,3 +306,118 @@ pub fn update_hits(
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "dim2")]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::math::{Dir3, Ray3d, Vec3};
+    use bevy::picking::backend::ray::RayId;
+    use bevy::picking::pointer::PointerId;
+    use bevy::prelude::{Events, Transform, TransformPlugin};
+    use bevy::time::TimePlugin;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn picking_hits_are_sorted_by_depth() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .add_event::<PointerHits>()
+        .insert_resource(RapierPickingSettings {
+            ray_cast_visibility: RapierCastVisibility::Any,
+            ..Default::default()
+        });
+        app.finish();
+
+        let world = app.world_mut();
+        let camera = world.spawn(Camera::default()).id();
+        let bottom = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 1.0), Collider::ball(10.0)))
+            .id();
+        let top = world
+            .spawn((Transform::from_xyz(0.0, 0.0, 2.0), Collider::ball(10.0)))
+            .id();
+        let sorted = world
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 0.0),
+                Collider::ball(10.0),
+                RapierPickingSortKey(3.0),
+            ))
+            .id();
+        let small = world
+            .spawn((Transform::from_xyz(4.0, 0.0, 0.0), Collider::ball(1.0)))
+            .id();
+        app.update();
+        app.update();
+
+        let pick = |app: &mut App| {
+            let mut ray_map = RayMap::default();
+            ray_map.map.insert(
+                RayId::new(camera, PointerId::Mouse),
+                Ray3d::new(Vec3::new(0.0, 0.0, 1000.0), Dir3::NEG_Z),
+            );
+            let world = app.world_mut();
+            world.insert_resource(ray_map);
+            world.run_system_once(update_hits).unwrap();
+            let mut hits: Vec<_> = world
+                .resource_mut::<Events<PointerHits>>()
+                .drain()
+                .flat_map(|hits| hits.picks)
+                .collect();
+            hits.sort_by(|a, b| a.1.depth.total_cmp(&b.1.depth));
+            hits.into_iter()
+                .map(|(entity, hit)| (entity, hit.depth))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            pick(&mut app),
+            vec![(sorted, 997.0), (top, 998.0), (bottom, 999.0)]
+        );
+
+        app.world_mut()
+            .resource_mut::<RapierPickingSettings>()
+            .pick_radius = Some(5.0);
+        let hits = pick(&mut app);
+        assert_eq!(hits.len(), 4);
+        assert_eq!(hits[3], (small, 1000.0));
+
+        app.world_mut().entity_mut(small).insert(Sensor);
+        app.world_mut()
+            .entity_mut(camera)
+            .insert(RapierPickingFilter {
+                exclude_sensors: true,
+                max_distance: 998.5,
+                ..Default::default()
+            });
+        app.update();
+        assert_eq!(
+            pick(&mut app),
+            vec![(sorted, 997.0), (top, 998.0)],
+            "The camera filter should exclude sensors and distant colliders"
+        );
+
+        let context = app
+            .world_mut()
+            .query_filtered::<Entity, With<DefaultRapierContext>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut()
+            .entity_mut(camera)
+            .insert(RapierContextEntityLink(context));
+        assert_eq!(pick(&mut app).len(), 2);
+        app.world_mut()
+            .entity_mut(camera)
+            .insert(RapierContextEntityLink(small));
+        assert!(
+            pick(&mut app).is_empty(),
+            "The camera should only pick into its linked context"
+        );
+    }
+}
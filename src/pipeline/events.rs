@@ -1,21 +1,30 @@
+use crate::geometry::{Friction, Restitution};
 use crate::math::{Real, Vect};
-use bevy::prelude::{Entity, Event};
-use rapier::dynamics::RigidBodySet;
+use bevy::prelude::{
+    Commands, Component, Entity, Event, Reflect, ReflectComponent, ReflectDefault,
+};
+use rapier::dynamics::{RigidBody, RigidBodySet};
 use rapier::geometry::{
-    ColliderHandle, ColliderSet, CollisionEvent as RapierCollisionEvent, CollisionEventFlags,
-    ContactForceEvent as RapierContactForceEvent, ContactPair,
+    Collider, ColliderHandle, ColliderSet, CollisionEvent as RapierCollisionEvent,
+    CollisionEventFlags, ContactForceEvent as RapierContactForceEvent, ContactPair, NarrowPhase,
 };
 use rapier::pipeline::EventHandler;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 
 #[cfg(doc)]
-use crate::prelude::{ActiveEvents, ContactForceEventThreshold};
+use crate::prelude::{
+    ActiveEvents, ContactForceEventThreshold, ImpactSpeedThreshold, RapierConfiguration, Sensor,
+};
 
 /// Events occurring when two colliders start or stop colliding
 ///
 /// This will only get triggered if the entity has the
 /// [`ActiveEvents::COLLISION_EVENTS`] flag enabled.
+///
+/// Besides being sent as a buffered event, it is triggered on the entity of the rapier context
+/// the colliders are part of, so the events of a single context can be handled by an observer on
+/// its entity, and [`Trigger::target`](bevy::prelude::Trigger::target) identifies the context.
 #[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CollisionEvent {
     /// Event occurring when two colliders start colliding
@@ -29,6 +38,9 @@ pub enum CollisionEvent {
 ///
 /// This will only get triggered if the entity has the
 /// [`ActiveEvents::CONTACT_FORCE_EVENTS`] flag enabled.
+///
+/// Like [`CollisionEvent`], it is also triggered on the entity of the rapier context of the
+/// colliders.
 #[derive(Event, Copy, Clone, Debug, PartialEq)]
 pub struct ContactForceEvent {
     /// The first collider involved in the contact.
@@ -49,6 +61,312 @@ pub struct ContactForceEvent {
     pub max_force_magnitude: Real,
 }
 
+/// A buffered event sent along with each [`CollisionEvent`] and [`ContactForceEvent`], with the
+/// entity of the rapier context of their colliders.
+///
+/// With multiple rapier contexts, read `EventReader<RapierContextEvent<CollisionEvent>>` instead of
+/// `EventReader<CollisionEvent>` to tell the events of each context apart.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct RapierContextEvent<E: Event> {
+    /// The entity of the rapier context the event comes from.
+    pub context: Entity,
+    /// The event.
+    pub event: E,
+}
+
+/// Event occurring when two colliders start colliding, with the details of their contact at the
+/// moment of the impact.
+///
+/// This is sent along with the [`CollisionEvent::Started`] event of the colliders if
+/// [`RapierConfiguration::collision_details`] is enabled. No details are sent for the collisions
+/// involving a [`Sensor`], since they have no contacts.
+///
+/// Like [`CollisionEvent`], it is also triggered on the entity of the rapier context of the
+/// colliders.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct CollisionStartDetails {
+    /// The first collider involved in the collision.
+    pub collider1: Entity,
+    /// The second collider involved in the collision.
+    pub collider2: Entity,
+    /// The rigid-body the first collider is attached to, if any.
+    pub rigid_body1: Option<Entity>,
+    /// The rigid-body the second collider is attached to, if any.
+    pub rigid_body2: Option<Entity>,
+    /// The deepest contact point, on the first collider, in world-space.
+    pub point: Vect,
+    /// The contact normal, pointing from the first collider towards the second one, in
+    /// world-space.
+    pub normal: Vect,
+    /// The penetration depth of the deepest contact point, negative if the colliders are
+    /// separated by a distance smaller than their prediction distance.
+    pub penetration_depth: Real,
+    /// The velocity of the second rigid-body relative to the first one at the contact point.
+    ///
+    /// Its component along `normal` is negative when the colliders are moving towards each other.
+    pub relative_velocity: Vect,
+    /// The flags of the collision.
+    pub flags: CollisionEventFlags,
+}
+
+/// Event occurring when two colliders hit each other, sent for the colliders with an
+/// [`ImpactSpeedThreshold`].
+///
+/// An impact is detected after each simulation substep, when the colliders touch and the speed at
+/// which they were moving towards each other exceeds the threshold, whether they just started
+/// touching or were already in contact. A pair of colliders staying above the threshold doesn't
+/// send new events until its speed falls below the threshold again. This doesn't require the
+/// [`ActiveEvents::COLLISION_EVENTS`](crate::geometry::ActiveEvents::COLLISION_EVENTS) flag.
+///
+/// Like [`CollisionEvent`], it is also triggered on the entity of the rapier context of the
+/// colliders.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct ImpactEvent {
+    /// The first collider involved in the impact.
+    pub collider1: Entity,
+    /// The second collider involved in the impact.
+    pub collider2: Entity,
+    /// The contact normal, pointing from the first collider towards the second one, in
+    /// world-space.
+    pub normal: Vect,
+    /// The speed at which the colliders were moving towards each other along the normal, right
+    /// before the impact.
+    ///
+    /// This is estimated from the velocities of their rigid-bodies at the contact point after the
+    /// simulation substep, and from the speed removed by the normal impulses given the masses of
+    /// the rigid-bodies, ignoring their rotation.
+    pub normal_speed: Real,
+    /// The sum of the normal impulses applied at the contact points during the simulation substep
+    /// of the impact.
+    pub total_impulse: Real,
+    /// The friction of the first collider.
+    pub friction1: Friction,
+    /// The friction of the second collider.
+    pub friction2: Friction,
+    /// The restitution of the first collider.
+    pub restitution1: Restitution,
+    /// The restitution of the second collider.
+    pub restitution2: Restitution,
+}
+
+/// Collects the impacts of the colliders with an [`ImpactSpeedThreshold`] during the last
+/// simulation substep into `events`.
+///
+/// `thresholds` contains the colliders with a threshold, and `impacting_pairs` contains the pairs
+/// of colliders above their threshold after the previous call.
+pub(crate) fn collect_impact_events(
+    narrow_phase: &NarrowPhase,
+    colliders: &ColliderSet,
+    bodies: &RigidBodySet,
+    thresholds: &HashMap<ColliderHandle, Real>,
+    impacting_pairs: &mut HashSet<(ColliderHandle, ColliderHandle)>,
+    events: &mut Vec<ImpactEvent>,
+) {
+    let body = |collider: &Collider| collider.parent().and_then(|handle| bodies.get(handle));
+    let inv_mass = |collider: &Collider| {
+        body(collider)
+            .filter(|body| body.is_dynamic() && body.mass() > 0.0)
+            .map_or(0.0, |body| 1.0 / body.mass())
+    };
+
+    let mut new_impacting_pairs = HashSet::new();
+    for (handle, threshold) in thresholds {
+        for contact_pair in narrow_phase.contact_pairs_with(*handle) {
+            let pair = (contact_pair.collider1, contact_pair.collider2);
+            if !contact_pair.has_any_active_contact || new_impacting_pairs.contains(&pair) {
+                continue;
+            }
+            let (Some(collider1), Some(collider2), Some((manifold, contact))) = (
+                colliders.get(pair.0),
+                colliders.get(pair.1),
+                contact_pair.find_deepest_contact(),
+            ) else {
+                continue;
+            };
+            let other_threshold = thresholds.get(if *handle == pair.0 { &pair.1 } else { &pair.0 });
+            let threshold = other_threshold.map_or(*threshold, |other| threshold.min(*other));
+
+            let total_impulse: Real = contact_pair
+                .manifolds
+                .iter()
+                .flat_map(|manifold| &manifold.points)
+                .map(|point| point.data.impulse)
+                .sum();
+            // Speculative contacts between colliders which are about to touch don't push them
+            // apart yet.
+            if total_impulse <= 0.0 {
+                continue;
+            }
+            let point = collider1.position() * contact.local_p1;
+            let normal = collider1.position() * manifold.local_n1;
+            let velocity = |collider: &Collider| {
+                body(collider)
+                    .map(|body| body.velocity_at_point(&point))
+                    .unwrap_or_default()
+            };
+            // The speed of the colliders before the step is their current approaching speed,
+            // increased by the speed removed by the normal impulses of the step.
+            let normal_speed = (velocity(collider1) - velocity(collider2)).dot(&normal)
+                + total_impulse * (inv_mass(collider1) + inv_mass(collider2));
+            if normal_speed < threshold {
+                continue;
+            }
+
+            new_impacting_pairs.insert(pair);
+            if impacting_pairs.contains(&pair) {
+                continue;
+            }
+            events.push(ImpactEvent {
+                collider1: Entity::from_bits(collider1.user_data as u64),
+                collider2: Entity::from_bits(collider2.user_data as u64),
+                normal: normal.into(),
+                normal_speed,
+                total_impulse,
+                friction1: Friction {
+                    coefficient: collider1.friction(),
+                    combine_rule: collider1.friction_combine_rule().into(),
+                },
+                friction2: Friction {
+                    coefficient: collider2.friction(),
+                    combine_rule: collider2.friction_combine_rule().into(),
+                },
+                restitution1: Restitution {
+                    coefficient: collider1.restitution(),
+                    combine_rule: collider1.restitution_combine_rule().into(),
+                },
+                restitution2: Restitution {
+                    coefficient: collider2.restitution(),
+                    combine_rule: collider2.restitution_combine_rule().into(),
+                },
+            });
+        }
+    }
+
+    *impacting_pairs = new_impacting_pairs;
+}
+
+/// Observer event triggered on a collider entity when it starts colliding with another collider.
+///
+/// This is triggered along with the [`CollisionEvent::Started`] event, on both colliders, and on
+/// their rigid-body entity if it has a [`PropagateCollisionEvents`] component.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OnCollisionStart {
+    /// The collider involved in the collision, attached to the observed entity or to one of its
+    /// descendants.
+    pub collider: Entity,
+    /// The other collider involved in the collision.
+    pub other: Entity,
+    /// The flags of the collision.
+    pub flags: CollisionEventFlags,
+}
+
+/// Observer event triggered on a collider entity when it stops colliding with another collider.
+///
+/// This is triggered along with the [`CollisionEvent::Stopped`] event, on both colliders, and on
+/// their rigid-body entity if it has a [`PropagateCollisionEvents`] component.
+#[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OnCollisionEnd {
+    /// The collider involved in the collision, attached to the observed entity or to one of its
+    /// descendants.
+    pub collider: Entity,
+    /// The other collider involved in the collision.
+    pub other: Entity,
+    /// The flags of the collision.
+    pub flags: CollisionEventFlags,
+}
+
+/// Observer event triggered on a collider entity when the contact forces with another collider
+/// exceed its [`ContactForceEventThreshold`].
+///
+/// This is triggered along with the [`ContactForceEvent`] event, on both colliders, and on their
+/// rigid-body entity if it has a [`PropagateCollisionEvents`] component.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct OnContactForce {
+    /// The collider involved in the contact, attached to the observed entity or to one of its
+    /// descendants.
+    pub collider: Entity,
+    /// The other collider involved in the contact.
+    pub other: Entity,
+    /// The contact forces, as reported by the [`ContactForceEvent`].
+    pub event: ContactForceEvent,
+}
+
+/// A marker component triggering the [`OnCollisionStart`], [`OnCollisionEnd`] and
+/// [`OnContactForce`] observer events of the colliders of a rigid-body on the rigid-body entity
+/// as well.
+///
+/// Events between two colliders of the same rigid-body are triggered twice on the rigid-body.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct PropagateCollisionEvents;
+
+/// Triggers the given collision and contact force events on the entity of their rapier context,
+/// and the corresponding observer events on their colliders.
+///
+/// `propagate_to` returns the rigid-body entity of a collider, if the events of the collider
+/// must also be triggered on it.
+pub(crate) fn trigger_collision_observers(
+    commands: &mut Commands,
+    context: Entity,
+    collision_events: &[CollisionEvent],
+    contact_force_events: &[ContactForceEvent],
+    propagate_to: impl Fn(Entity) -> Option<Entity>,
+) {
+    let targets = |collider: Entity| {
+        let rigid_body = propagate_to(collider).filter(|body| *body != collider);
+        std::iter::once(collider)
+            .chain(rigid_body)
+            .collect::<Vec<_>>()
+    };
+
+    for event in collision_events {
+        commands.trigger_targets(*event, context);
+        match *event {
+            CollisionEvent::Started(e1, e2, flags) => {
+                for (collider, other) in [(e1, e2), (e2, e1)] {
+                    commands.trigger_targets(
+                        OnCollisionStart {
+                            collider,
+                            other,
+                            flags,
+                        },
+                        targets(collider),
+                    );
+                }
+            }
+            CollisionEvent::Stopped(e1, e2, flags) => {
+                for (collider, other) in [(e1, e2), (e2, e1)] {
+                    commands.trigger_targets(
+                        OnCollisionEnd {
+                            collider,
+                            other,
+                            flags,
+                        },
+                        targets(collider),
+                    );
+                }
+            }
+        }
+    }
+
+    for event in contact_force_events {
+        commands.trigger_targets(*event, context);
+        for (collider, other) in [
+            (event.collider1, event.collider2),
+            (event.collider2, event.collider1),
+        ] {
+            commands.trigger_targets(
+                OnContactForce {
+                    collider,
+                    other,
+                    event: *event,
+                },
+                targets(collider),
+            );
+        }
+    }
+}
+
 // TODO: it may be more efficient to use crossbeam channel.
 // However crossbeam channels cause a Segfault (I have not
 // investigated how to reproduce this exactly to open an
@@ -58,7 +376,11 @@ pub(crate) struct EventQueue<'a> {
     // Used to retrieve the entity of colliders that have been removed from the simulation
     // since the last physics step.
     pub deleted_colliders: &'a HashMap<ColliderHandle, Entity>,
+    // Whether the details of the starting collisions are collected, see
+    // `RapierConfiguration::collision_details`.
+    pub collect_collision_details: bool,
     pub collision_events: RwLock<Vec<CollisionEvent>>,
+    pub collision_details: RwLock<Vec<CollisionStartDetails>>,
     pub contact_force_events: RwLock<Vec<ContactForceEvent>>,
 }
 
@@ -70,16 +392,61 @@ impl EventQueue<'_> {
             .or_else(|| self.deleted_colliders.get(&handle).copied())
             .expect("Internal error: entity not found for collision event.")
     }
+
+    fn collision_details(
+        &self,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        contact_pair: &ContactPair,
+        flags: CollisionEventFlags,
+    ) -> Option<CollisionStartDetails> {
+        let (manifold, contact) = contact_pair.find_deepest_contact()?;
+        let collider1 = colliders.get(contact_pair.collider1)?;
+        let collider2 = colliders.get(contact_pair.collider2)?;
+        let body1 = collider1.parent().and_then(|handle| bodies.get(handle));
+        let body2 = collider2.parent().and_then(|handle| bodies.get(handle));
+
+        let point = collider1.position() * contact.local_p1;
+        let velocity = |body: Option<&RigidBody>| {
+            body.map(|body| body.velocity_at_point(&point))
+                .unwrap_or_default()
+        };
+        let body_entity =
+            |body: Option<&RigidBody>| body.map(|body| Entity::from_bits(body.user_data as u64));
+
+        Some(CollisionStartDetails {
+            collider1: self.collider2entity(colliders, contact_pair.collider1),
+            collider2: self.collider2entity(colliders, contact_pair.collider2),
+            rigid_body1: body_entity(body1),
+            rigid_body2: body_entity(body2),
+            point: point.into(),
+            normal: (collider1.position() * manifold.local_n1).into(),
+            penetration_depth: -contact.dist,
+            relative_velocity: (velocity(body2) - velocity(body1)).into(),
+            flags,
+        })
+    }
 }
 
 impl EventHandler for EventQueue<'_> {
     fn handle_collision_event(
         &self,
-        _bodies: &RigidBodySet,
+        bodies: &RigidBodySet,
         colliders: &ColliderSet,
         event: RapierCollisionEvent,
-        _: Option<&ContactPair>,
+        contact_pair: Option<&ContactPair>,
     ) {
+        if let (true, RapierCollisionEvent::Started(_, _, flags), Some(contact_pair)) =
+            (self.collect_collision_details, event, contact_pair)
+        {
+            let details = self.collision_details(bodies, colliders, contact_pair, flags);
+            if let (Some(details), Ok(mut collision_details)) =
+                (details, self.collision_details.write())
+            {
+                collision_details.push(details);
+            }
+        }
+
         let event = match event {
             RapierCollisionEvent::Started(h1, h2, flags) => {
                 let e1 = self.collider2entity(colliders, h1);
@@ -299,4 +666,344 @@ mod test {
             commands.entity(entity).despawn();
         }
     }
+
+    #[test]
+    pub fn collision_observers_triggered() {
+        use bevy::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Triggered(Vec<(&'static str, Entity, Entity)>);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .init_resource::<Triggered>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1f32 / 60f32),
+        ));
+        app.finish();
+
+        let world = app.world_mut();
+        let ground = world
+            .spawn((Transform::from_xyz(0.0, -1.2, 0.0), cuboid(4.0, 1.0, 1.0)))
+            .observe(
+                |trigger: Trigger<OnCollisionStart>, mut triggered: ResMut<Triggered>| {
+                    triggered
+                        .0
+                        .push(("ground", trigger.target(), trigger.other));
+                },
+            )
+            .id();
+        let body = world
+            .spawn((
+                Transform::from_xyz(0.0, 2.0, 0.0),
+                RigidBody::Dynamic,
+                PropagateCollisionEvents,
+            ))
+            .observe(
+                |trigger: Trigger<OnCollisionStart>, mut triggered: ResMut<Triggered>| {
+                    triggered
+                        .0
+                        .push(("body", trigger.target(), trigger.collider));
+                },
+            )
+            .id();
+        let collider = world
+            .spawn((
+                Transform::default(),
+                cuboid(0.5, 0.5, 0.5),
+                ActiveEvents::COLLISION_EVENTS,
+                ChildOf(body),
+            ))
+            .id();
+
+        for _ in 0..120 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            vec![("ground", ground, collider), ("body", body, collider)]
+        );
+    }
+
+    #[test]
+    pub fn collision_details_sent() {
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1f32 / 60f32),
+        ));
+        app.finish();
+        app.update();
+        app.world_mut()
+            .query::<&mut RapierConfiguration>()
+            .single_mut(app.world_mut())
+            .unwrap()
+            .collision_details = true;
+
+        let world = app.world_mut();
+        let ground = world
+            .spawn((Transform::from_xyz(0.0, -1.0, 0.0), cuboid(4.0, 1.0, 1.0)))
+            .id();
+        let ball = world
+            .spawn((
+                Transform::from_xyz(0.0, 2.0, 0.0),
+                RigidBody::Dynamic,
+                cuboid(0.5, 0.5, 0.5),
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+
+        let mut details = Vec::new();
+        for _ in 0..120 {
+            app.update();
+            details.extend(
+                app.world_mut()
+                    .resource_mut::<Events<CollisionStartDetails>>()
+                    .drain(),
+            );
+        }
+        assert_eq!(details.len(), 1);
+        let details = details[0];
+        let (ground_normal, relative_velocity) = if details.collider1 == ground {
+            assert_eq!(details.collider2, ball);
+            assert_eq!(details.rigid_body2, Some(ball));
+            (details.normal, details.relative_velocity)
+        } else {
+            assert_eq!((details.collider1, details.collider2), (ball, ground));
+            assert_eq!(details.rigid_body1, Some(ball));
+            (-details.normal, -details.relative_velocity)
+        };
+        assert!((ground_normal - Vect::Y).length() < 1.0e-3);
+        assert!(details.point.y.abs() < 0.1);
+        assert!(
+            relative_velocity.y < -5.0,
+            "The ball should hit the ground at its falling speed, got {relative_velocity}"
+        );
+    }
+
+    fn impact_app(substeps: usize) -> App {
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps,
+        });
+        app.finish();
+        app
+    }
+
+    fn drain_impacts(app: &mut App, updates: usize) -> Vec<ImpactEvent> {
+        use bevy::prelude::*;
+
+        let mut impacts = Vec::new();
+        for _ in 0..updates {
+            app.update();
+            impacts.extend(
+                app.world_mut()
+                    .resource_mut::<Events<ImpactEvent>>()
+                    .drain(),
+            );
+        }
+        impacts
+    }
+
+    #[test]
+    pub fn impact_events_sent() {
+        use bevy::prelude::*;
+
+        // The impact must be detected at the substep the colliders start touching.
+        for substeps in [1, 4] {
+            let mut app = impact_app(substeps);
+            let world = app.world_mut();
+            let ground = world
+                .spawn((
+                    Transform::from_xyz(0.0, -1.0, 0.0),
+                    cuboid(8.0, 1.0, 1.0),
+                    Friction::new(0.7),
+                ))
+                .id();
+            let hard_impact = world
+                .spawn((
+                    Transform::from_xyz(-2.0, 2.0, 0.0),
+                    RigidBody::Dynamic,
+                    cuboid(0.5, 0.5, 0.5),
+                    ImpactSpeedThreshold(2.0),
+                ))
+                .id();
+            world.spawn((
+                Transform::from_xyz(2.0, 2.0, 0.0),
+                RigidBody::Dynamic,
+                cuboid(0.5, 0.5, 0.5),
+                ImpactSpeedThreshold(100.0),
+            ));
+
+            let impacts = drain_impacts(&mut app, 180);
+            assert_eq!(impacts.len(), 1, "{substeps} substeps: {impacts:?}");
+            let impact = impacts[0];
+            let (ground_friction, normal) = if impact.collider1 == ground {
+                assert_eq!(impact.collider2, hard_impact);
+                (impact.friction1, impact.normal)
+            } else {
+                assert_eq!((impact.collider1, impact.collider2), (hard_impact, ground));
+                (impact.friction2, -impact.normal)
+            };
+            assert_eq!(ground_friction.coefficient, 0.7);
+            assert!((normal - Vect::Y).length() < 1.0e-3);
+            // The box falls from a height of 1.5 before touching the ground.
+            let falling_speed = (2.0 * 9.81 * 1.5f32).sqrt();
+            assert!(
+                (impact.normal_speed - falling_speed).abs() < 0.5,
+                "{substeps} substeps: {impact:?}"
+            );
+            assert!(impact.total_impulse > 0.0, "{impact:?}");
+        }
+    }
+
+    #[test]
+    pub fn impact_events_sent_for_resting_contacts() {
+        use bevy::prelude::*;
+
+        let mut app = impact_app(1);
+        let world = app.world_mut();
+        world.spawn((Transform::from_xyz(0.0, -1.0, 0.0), cuboid(8.0, 1.0, 1.0)));
+        let resting = world
+            .spawn((
+                Transform::from_xyz(0.0, 0.5, 0.0),
+                RigidBody::Dynamic,
+                cuboid(0.5, 0.5, 0.5),
+                ImpactSpeedThreshold(2.0),
+                ExternalImpulse::default(),
+            ))
+            .id();
+        assert!(
+            drain_impacts(&mut app, 60).is_empty(),
+            "A resting contact isn't an impact"
+        );
+
+        // The box has a unit mass, in 2D and 3D.
+        app.world_mut()
+            .get_mut::<ExternalImpulse>(resting)
+            .unwrap()
+            .impulse = -Vect::Y * 5.0;
+        let impacts = drain_impacts(&mut app, 60);
+        assert_eq!(impacts.len(), 1, "{impacts:?}");
+        assert!(
+            (impacts[0].normal_speed - 5.0).abs() < 0.5,
+            "{:?}",
+            impacts[0]
+        );
+    }
+
+    #[test]
+    pub fn events_triggered_on_their_context() {
+        use bevy::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct ContextEvents(Vec<(Entity, Entity)>);
+        #[derive(Resource, Default)]
+        struct BufferedContextEvents(Vec<(Entity, Entity, Entity)>);
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .init_resource::<ContextEvents>()
+        .init_resource::<BufferedContextEvents>()
+        .add_systems(
+            PostUpdate,
+            (|mut reader: EventReader<RapierContextEvent<CollisionEvent>>,
+              mut events: ResMut<BufferedContextEvents>| {
+                for event in reader.read() {
+                    if let CollisionEvent::Started(e1, e2, _) = event.event {
+                        events.0.push((event.context, e1, e2));
+                    }
+                }
+            })
+            .after(PhysicsSet::StepSimulation),
+        )
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1f32 / 60f32),
+        ));
+        app.finish();
+        app.update();
+
+        let world = app.world_mut();
+        let other_context = world
+            .spawn(RapierContextSimulation::default())
+            .observe(
+                |trigger: Trigger<CollisionEvent>, mut events: ResMut<ContextEvents>| {
+                    if let CollisionEvent::Started(e1, e2, _) = *trigger {
+                        events.0.push((e1, e2));
+                    }
+                },
+            )
+            .id();
+        app.update();
+
+        let world = app.world_mut();
+        let mut spawn_fall = |context: Option<Entity>| {
+            let ground = world
+                .spawn((Transform::from_xyz(0.0, -1.0, 0.0), cuboid(4.0, 1.0, 1.0)))
+                .id();
+            let falling = world
+                .spawn((
+                    Transform::from_xyz(0.0, 2.0, 0.0),
+                    RigidBody::Dynamic,
+                    cuboid(0.5, 0.5, 0.5),
+                    ActiveEvents::COLLISION_EVENTS,
+                ))
+                .id();
+            if let Some(context) = context {
+                for entity in [ground, falling] {
+                    world
+                        .entity_mut(entity)
+                        .insert(RapierContextEntityLink(context));
+                }
+            }
+            (ground, falling)
+        };
+        let (default_ground, default_falling) = spawn_fall(None);
+        let (ground, falling) = spawn_fall(Some(other_context));
+
+        for _ in 0..120 {
+            app.update();
+        }
+        let events = &app.world().resource::<ContextEvents>().0;
+        assert_eq!(events.len(), 1);
+        assert!(events[0] == (ground, falling) || events[0] == (falling, ground));
+
+        let world = app.world_mut();
+        let default_context = world
+            .query_filtered::<Entity, With<DefaultRapierContext>>()
+            .single(world)
+            .unwrap();
+        let mut buffered = app.world().resource::<BufferedContextEvents>().0.clone();
+        buffered.sort_by_key(|(context, _, _)| *context != default_context);
+        assert_eq!(buffered.len(), 2);
+        for ((context, e1, e2), (expected_context, expected)) in buffered.into_iter().zip([
+            (default_context, [default_ground, default_falling]),
+            (other_context, [ground, falling]),
+        ]) {
+            assert_eq!(context, expected_context);
+            assert!([e1, e2] == expected || [e2, e1] == expected);
+        }
+    }
 }
@@ -1,5 +1,8 @@
-pub(crate) use self::events::EventQueue;
-pub use self::events::{CollisionEvent, ContactForceEvent};
+pub(crate) use self::events::{collect_impact_events, trigger_collision_observers, EventQueue};
+pub use self::events::{
+    CollisionEvent, CollisionStartDetails, ContactForceEvent, ImpactEvent, OnCollisionEnd,
+    OnCollisionStart, OnContactForce, PropagateCollisionEvents, RapierContextEvent,
+};
 pub(crate) use self::physics_hooks::BevyPhysicsHooksAdapter;
 pub use self::physics_hooks::{
     BevyPhysicsHooks, ContactModificationContextView, PairFilterContextView,
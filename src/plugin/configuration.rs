@@ -8,9 +8,13 @@ use bevy::{
 use crate::math::{Real, Vect};
 
 #[cfg(doc)]
-use {crate::prelude::TransformInterpolation, rapier::dynamics::IntegrationParameters};
+use {
+    crate::prelude::{CollisionEvent, CollisionStartDetails, TransformInterpolation},
+    rapier::dynamics::IntegrationParameters,
+};
 
 /// The different ways of adjusting the timestep length each frame.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Resource)]
 pub enum TimestepMode {
     /// Use a fixed timestep: the physics simulation will be advanced by the fixed value
@@ -79,6 +83,11 @@ pub struct RapierConfiguration {
     pub scaled_shape_subdivision: u32,
     /// Specifies if backend sync should always accept transform changes, which may be from the writeback stage.
     pub force_update_from_transform_changes: bool,
+    /// Specifies if a [`CollisionStartDetails`] event, with the contact details of the impact, is
+    /// sent along with each [`CollisionEvent::Started`] event between non-sensor colliders.
+    ///
+    /// Disabled by default.
+    pub collision_details: bool,
 }
 
 impl RapierConfiguration {
@@ -95,6 +104,7 @@ impl RapierConfiguration {
             query_pipeline_active: true,
             scaled_shape_subdivision: 10,
             force_update_from_transform_changes: false,
+            collision_details: false,
         }
     }
 }
@@ -2,8 +2,15 @@
 
 pub mod systemparams;
 
+mod query_log;
+mod remap;
+mod snapshot;
+pub use query_log::{LoggedHit, LoggedQuery, QueryLog};
+pub use remap::RemapRapierContext;
+pub use snapshot::RapierSnapshot;
+
 use bevy::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::RwLock;
 
 use rapier::prelude::{
@@ -15,7 +22,10 @@ use rapier::prelude::{
 
 use crate::geometry::{Collider, PointProjection, RayIntersection, ShapeCastHit};
 use crate::math::{Rot, Vect};
-use crate::pipeline::{CollisionEvent, ContactForceEvent, EventQueue, QueryFilter};
+use crate::pipeline::{
+    collect_impact_events, CollisionEvent, CollisionStartDetails, ContactForceEvent, EventQueue,
+    ImpactEvent, QueryFilter,
+};
 use bevy::prelude::{Entity, EventWriter, GlobalTransform, Query};
 
 use crate::control::{CharacterCollision, MoveShapeOptions, MoveShapeOutput};
@@ -66,7 +76,6 @@ pub struct RapierContextEntityLink(pub Entity);
 pub struct RapierContextColliders {
     /// The set of colliders part of the simulation.
     pub colliders: ColliderSet,
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) entity2collider: HashMap<Entity, ColliderHandle>,
 }
 
@@ -137,9 +146,7 @@ pub struct RapierContextJoints {
     /// The set of multibody joints part of the simulation.
     pub multibody_joints: MultibodyJointSet,
 
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) entity2impulse_joint: HashMap<Entity, ImpulseJointHandle>,
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) entity2multibody_joint: HashMap<Entity, MultibodyJointHandle>,
 }
 
@@ -163,9 +170,30 @@ impl RapierContextJoints {
 pub struct RapierQueryPipeline {
     /// The query pipeline, which performs scene queries (ray-casting, point projection, etc.)
     pub query_pipeline: QueryPipeline,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    query_log: Option<QueryLog>,
 }
 
 impl RapierQueryPipeline {
+    /// Starts logging the scene queries made through this pipeline, see [`QueryLog`].
+    ///
+    /// The queries logged by [`Self::cast_ray`], [`Self::cast_ray_and_get_normal`],
+    /// [`Self::cast_shape`], [`Self::intersections_with_shape`] and [`Self::project_point`] are
+    /// rendered by the debug-renderer when configured to do so.
+    pub fn enable_query_log(&mut self) {
+        self.query_log.get_or_insert_with(QueryLog::default);
+    }
+
+    /// Stops logging the scene queries made through this pipeline, and discards the logged queries.
+    pub fn disable_query_log(&mut self) {
+        self.query_log = None;
+    }
+
+    /// The log of the scene queries made through this pipeline, if enabled.
+    pub fn query_log(&self) -> Option<&QueryLog> {
+        self.query_log.as_ref()
+    }
+
     /// Updates the state of the query pipeline, based on the collider positions known
     /// from the last timestep or the last call to `self.propagate_modified_body_positions_to_colliders()`.
     pub fn update_query_pipeline(&mut self, colliders: &RapierContextColliders) {
@@ -196,8 +224,8 @@ impl RapierQueryPipeline {
     ) -> Option<(Entity, Real)> {
         let ray = Ray::new(ray_origin.into(), ray_dir.into());
 
-        let (h, toi) =
-            rigidbody_set.with_query_filter(rapier_colliders, filter, move |filter| {
+        let result = rigidbody_set
+            .with_query_filter(rapier_colliders, filter, move |filter| {
                 self.query_pipeline.cast_ray(
                     &rigidbody_set.bodies,
                     &rapier_colliders.colliders,
@@ -206,9 +234,23 @@ impl RapierQueryPipeline {
                     solid,
                     filter,
                 )
-            })?;
+            })
+            .and_then(|(h, toi)| rapier_colliders.collider_entity(h).map(|e| (e, toi)));
 
-        rapier_colliders.collider_entity(h).map(|e| (e, toi))
+        if let Some(query_log) = &self.query_log {
+            query_log.push(LoggedQuery::Ray {
+                origin: ray_origin,
+                dir: ray_dir,
+                max_toi,
+                hit: result.map(|(entity, toi)| LoggedHit {
+                    entity,
+                    point: ray_origin + ray_dir * toi,
+                    normal: None,
+                    time_of_impact: toi,
+                }),
+            });
+        }
+        result
     }
 
     /// Find the closest intersection between a ray and a set of collider.
@@ -235,8 +277,8 @@ impl RapierQueryPipeline {
     ) -> Option<(Entity, RayIntersection)> {
         let ray = Ray::new(ray_origin.into(), ray_dir.into());
 
-        let (h, result) =
-            rigidbody_set.with_query_filter(rapier_colliders, filter, move |filter| {
+        let result = rigidbody_set
+            .with_query_filter(rapier_colliders, filter, move |filter| {
                 self.query_pipeline.cast_ray_and_get_normal(
                     &rigidbody_set.bodies,
                     &rapier_colliders.colliders,
@@ -245,11 +287,27 @@ impl RapierQueryPipeline {
                     solid,
                     filter,
                 )
-            })?;
-
-        rapier_colliders
-            .collider_entity(h)
-            .map(|e| (e, RayIntersection::from_rapier(result, ray_origin, ray_dir)))
+            })
+            .and_then(|(h, result)| {
+                rapier_colliders
+                    .collider_entity(h)
+                    .map(|e| (e, RayIntersection::from_rapier(result, ray_origin, ray_dir)))
+            });
+
+        if let Some(query_log) = &self.query_log {
+            query_log.push(LoggedQuery::Ray {
+                origin: ray_origin,
+                dir: ray_dir,
+                max_toi,
+                hit: result.map(|(entity, intersection)| LoggedHit {
+                    entity,
+                    point: intersection.point,
+                    normal: Some(intersection.normal),
+                    time_of_impact: intersection.time_of_impact,
+                }),
+            });
+        }
+        result
     }
 
     /// Find the all intersections between a ray and a set of collider and passes them to a callback.
@@ -351,8 +409,8 @@ impl RapierQueryPipeline {
         solid: bool,
         filter: QueryFilter,
     ) -> Option<(Entity, PointProjection)> {
-        let (h, result) =
-            rigidbody_set.with_query_filter(rapier_colliders, filter, move |filter| {
+        let result = rigidbody_set
+            .with_query_filter(rapier_colliders, filter, move |filter| {
                 self.query_pipeline.project_point(
                     &rigidbody_set.bodies,
                     &rapier_colliders.colliders,
@@ -360,11 +418,25 @@ impl RapierQueryPipeline {
                     solid,
                     filter,
                 )
-            })?;
-
-        rapier_colliders
-            .collider_entity(h)
-            .map(|e| (e, PointProjection::from_rapier(result)))
+            })
+            .and_then(|(h, result)| {
+                rapier_colliders
+                    .collider_entity(h)
+                    .map(|e| (e, PointProjection::from_rapier(result)))
+            });
+
+        if let Some(query_log) = &self.query_log {
+            query_log.push(LoggedQuery::PointProjection {
+                point,
+                hit: result.map(|(entity, projection)| LoggedHit {
+                    entity,
+                    point: projection.point,
+                    normal: None,
+                    time_of_impact: point.distance(projection.point),
+                }),
+            });
+        }
+        result
     }
 
     /// Find all the colliders containing the given point.
@@ -500,8 +572,8 @@ impl RapierQueryPipeline {
         //       RapierConfiguration::scaled_shape_subdivision here.
         scaled_shape.set_scale(shape.scale, 20);
 
-        let (h, result) =
-            rigidbody_set.with_query_filter(rapier_colliders, filter, move |filter| {
+        let result = rigidbody_set
+            .with_query_filter(rapier_colliders, filter, |filter| {
                 self.query_pipeline.cast_shape(
                     &rigidbody_set.bodies,
                     &rapier_colliders.colliders,
@@ -511,14 +583,39 @@ impl RapierQueryPipeline {
                     options,
                     filter,
                 )
-            })?;
-
-        rapier_colliders.collider_entity(h).map(|e| {
-            (
-                e,
-                ShapeCastHit::from_rapier(result, options.compute_impact_geometry_on_penetration),
-            )
-        })
+            })
+            .and_then(|(h, result)| {
+                rapier_colliders.collider_entity(h).map(|e| {
+                    (
+                        e,
+                        ShapeCastHit::from_rapier(
+                            result,
+                            options.compute_impact_geometry_on_penetration,
+                        ),
+                    )
+                })
+            });
+
+        if let Some(query_log) = &self.query_log {
+            query_log.push(LoggedQuery::ShapeCast {
+                shape: scaled_shape,
+                position: shape_pos,
+                rotation: shape_rot,
+                velocity: shape_vel,
+                max_toi: options.max_time_of_impact,
+                hit: result.map(|(entity, hit)| LoggedHit {
+                    entity,
+                    point: hit
+                        .details
+                        .map_or(shape_pos + shape_vel * hit.time_of_impact, |details| {
+                            details.witness1
+                        }),
+                    normal: hit.details.map(|details| details.normal1),
+                    time_of_impact: hit.time_of_impact,
+                }),
+            });
+        }
+        result
     }
 
     /* TODO: we need to wrap the NonlinearRigidMotion somehow.
@@ -598,16 +695,20 @@ impl RapierQueryPipeline {
         //       RapierConfiguration::scaled_shape_subdivision here.
         scaled_shape.set_scale(shape.scale, 20);
 
-        #[allow(clippy::redundant_closure)]
-        // False-positive, we can't move callback, closure becomes `FnOnce`
+        let mut entities = vec![];
         let callback = |h| {
             rapier_colliders
                 .collider_entity(h)
-                .map(|e| callback(e))
+                .map(|e| {
+                    if self.query_log.is_some() {
+                        entities.push(e);
+                    }
+                    callback(e)
+                })
                 .unwrap_or(true)
         };
 
-        rigidbody_set.with_query_filter(rapier_colliders, filter, move |filter| {
+        rigidbody_set.with_query_filter(rapier_colliders, filter, |filter| {
             self.query_pipeline.intersections_with_shape(
                 &rigidbody_set.bodies,
                 &rapier_colliders.colliders,
@@ -617,6 +718,15 @@ impl RapierQueryPipeline {
                 callback,
             )
         });
+
+        if let Some(query_log) = &self.query_log {
+            query_log.push(LoggedQuery::ShapeIntersections {
+                shape: scaled_shape,
+                position: shape_pos,
+                rotation: shape_rot,
+                entities,
+            });
+        }
     }
     /// Without borrowing the [`RapierContext`], calls the closure `f` once
     /// after converting the given [`QueryFilter`] into a raw [`RapierQueryFilter`].
@@ -662,12 +772,15 @@ pub struct RapierRigidBodySet {
     /// The set of rigid-bodies part of the simulation.
     pub bodies: RigidBodySet,
     /// NOTE: this map is needed to handle despawning.
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) entity2body: HashMap<Entity, RigidBodyHandle>,
 
     /// For transform change detection.
-    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) last_body_transform_set: HashMap<RigidBodyHandle, GlobalTransform>,
+
+    /// Set when a [`RapierSnapshot`] was restored, so the bevy components of every rigid-body
+    /// get re-synchronized with the restored state before user changes are applied.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) restored_from_snapshot: bool,
 }
 
 impl RapierRigidBodySet {
@@ -769,8 +882,25 @@ pub struct RapierContextSimulation {
 
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) collision_events_to_send: Vec<CollisionEvent>,
+    // Whether the details of the starting collisions are collected during the next steps, set from
+    // `RapierConfiguration::collision_details`.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) collect_collision_details: bool,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) collision_details_to_send: Vec<CollisionStartDetails>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) contact_force_events_to_send: Vec<ContactForceEvent>,
+    // The simulation time advanced by the last call to `step_simulation`, in seconds.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) simulated_time: Real,
+    // The impact speed thresholds of the colliders, set from their `ImpactSpeedThreshold`.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) impact_thresholds: HashMap<ColliderHandle, Real>,
+    // The pairs of colliders whose impact speed was above their threshold after the last substep.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) impacting_pairs: HashSet<(ColliderHandle, ColliderHandle)>,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) impact_events_to_send: Vec<ImpactEvent>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) character_collisions_collector: Vec<rapier::control::CharacterCollision>,
 }
@@ -787,7 +917,13 @@ impl Default for RapierContextSimulation {
             event_handler: None,
             deleted_colliders: HashMap::default(),
             collision_events_to_send: Vec::new(),
+            collect_collision_details: false,
+            collision_details_to_send: Vec::new(),
             contact_force_events_to_send: Vec::new(),
+            simulated_time: 0.0,
+            impact_thresholds: HashMap::default(),
+            impacting_pairs: HashSet::default(),
+            impact_events_to_send: Vec::new(),
             character_collisions_collector: Vec::new(),
         }
     }
@@ -817,7 +953,9 @@ impl RapierContextSimulation {
         let event_queue = if events.is_some() {
             Some(EventQueue {
                 deleted_colliders: &self.deleted_colliders,
+                collect_collision_details: self.collect_collision_details,
                 collision_events: RwLock::new(Vec::new()),
+                collision_details: RwLock::new(Vec::new()),
                 contact_force_events: RwLock::new(Vec::new()),
             })
         } else {
@@ -831,6 +969,7 @@ impl RapierContextSimulation {
             .unwrap_or(&() as &dyn EventHandler);
 
         let mut executed_steps = 0;
+        self.simulated_time = 0.0;
         match timestep_mode {
             TimestepMode::Interpolated {
                 dt,
@@ -878,6 +1017,15 @@ impl RapierContextSimulation {
                             event_handler,
                         );
                         executed_steps += 1;
+                        self.simulated_time += substep_integration_parameters.dt;
+                        collect_impact_events(
+                            &self.narrow_phase,
+                            &colliders.colliders,
+                            &rigidbody_set.bodies,
+                            &self.impact_thresholds,
+                            &mut self.impacting_pairs,
+                            &mut self.impact_events_to_send,
+                        );
                     }
 
                     sim_to_render_time.diff -= dt;
@@ -910,6 +1058,15 @@ impl RapierContextSimulation {
                         event_handler,
                     );
                     executed_steps += 1;
+                    self.simulated_time += substep_integration_parameters.dt;
+                    collect_impact_events(
+                        &self.narrow_phase,
+                        &colliders.colliders,
+                        &rigidbody_set.bodies,
+                        &self.impact_thresholds,
+                        &mut self.impacting_pairs,
+                        &mut self.impact_events_to_send,
+                    );
                 }
             }
             TimestepMode::Fixed { dt, substeps } => {
@@ -935,6 +1092,15 @@ impl RapierContextSimulation {
                         event_handler,
                     );
                     executed_steps += 1;
+                    self.simulated_time += substep_integration_parameters.dt;
+                    collect_impact_events(
+                        &self.narrow_phase,
+                        &colliders.colliders,
+                        &rigidbody_set.bodies,
+                        &self.impact_thresholds,
+                        &mut self.impacting_pairs,
+                        &mut self.impact_events_to_send,
+                    );
                 }
             }
         }
@@ -943,6 +1109,8 @@ impl RapierContextSimulation {
             // within `self.pipeline.step` called above, so we can unwrap here safely.
             self.collision_events_to_send =
                 std::mem::take(event_queue.collision_events.get_mut().unwrap());
+            self.collision_details_to_send =
+                std::mem::take(event_queue.collision_details.get_mut().unwrap());
             self.contact_force_events_to_send =
                 std::mem::take(event_queue.contact_force_events.get_mut().unwrap());
         }
@@ -966,6 +1134,19 @@ impl RapierContextSimulation {
         }
     }
 
+    /// Generates bevy events with the contact details of the collisions that started during the
+    /// last simulation step.
+    ///
+    /// See [`RapierConfiguration::collision_details`].
+    pub fn send_collision_details(
+        &mut self,
+        collision_details_writer: &mut EventWriter<CollisionStartDetails>,
+    ) {
+        for details in self.collision_details_to_send.drain(..) {
+            collision_details_writer.write(details);
+        }
+    }
+
     /// Attempts to move shape, optionally sliding or climbing obstacles.
     ///
     /// # Parameters
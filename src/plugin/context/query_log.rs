@@ -0,0 +1,217 @@
+//! Opt-in log of the scene queries made through a [`RapierQueryPipeline`].
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::Entity;
+use rapier::prelude::Real;
+
+use crate::geometry::Collider;
+use crate::math::{Rot, Vect};
+
+#[cfg(doc)]
+use super::RapierQueryPipeline;
+
+/// A hit reported by a [`LoggedQuery`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LoggedHit {
+    /// The entity of the collider hit by the query.
+    pub entity: Entity,
+    /// The hit point, in world-space.
+    pub point: Vect,
+    /// The normal at the hit point, if computed by the query.
+    pub normal: Option<Vect>,
+    /// The time-of-impact of the ray or shape cast, or the distance between the projected point
+    /// and its projection.
+    pub time_of_impact: Real,
+}
+
+/// A scene query made through a [`RapierQueryPipeline`] whose [`QueryLog`] is enabled.
+#[derive(Clone, Debug)]
+pub enum LoggedQuery {
+    /// A ray cast made with [`RapierQueryPipeline::cast_ray`] or
+    /// [`RapierQueryPipeline::cast_ray_and_get_normal`].
+    Ray {
+        /// The origin of the ray.
+        origin: Vect,
+        /// The direction of the ray.
+        dir: Vect,
+        /// The maximum time-of-impact of the ray.
+        max_toi: Real,
+        /// The closest hit, if any.
+        hit: Option<LoggedHit>,
+    },
+    /// A shape cast made with [`RapierQueryPipeline::cast_shape`].
+    ShapeCast {
+        /// The cast shape.
+        shape: Collider,
+        /// The initial position of the shape.
+        position: Vect,
+        /// The rotation of the shape.
+        rotation: Rot,
+        /// The velocity of the shape.
+        velocity: Vect,
+        /// The maximum time-of-impact of the cast.
+        max_toi: Real,
+        /// The first hit, if any. Its point is the witness point on the hit collider if computed,
+        /// or the position of the shape at the time of impact otherwise.
+        hit: Option<LoggedHit>,
+    },
+    /// An intersection test made with [`RapierQueryPipeline::intersections_with_shape`].
+    ShapeIntersections {
+        /// The tested shape.
+        shape: Collider,
+        /// The position of the shape.
+        position: Vect,
+        /// The rotation of the shape.
+        rotation: Rot,
+        /// The entities of the colliders intersecting the shape, until the callback stopped the
+        /// query.
+        entities: Vec<Entity>,
+    },
+    /// A point projection made with [`RapierQueryPipeline::project_point`].
+    PointProjection {
+        /// The projected point.
+        point: Vect,
+        /// The projection on the closest collider, if any.
+        hit: Option<LoggedHit>,
+    },
+}
+
+impl LoggedQuery {
+    /// Whether this query hit at least one collider.
+    pub fn is_hit(&self) -> bool {
+        match self {
+            Self::Ray { hit, .. }
+            | Self::ShapeCast { hit, .. }
+            | Self::PointProjection { hit, .. } => hit.is_some(),
+            Self::ShapeIntersections { entities, .. } => !entities.is_empty(),
+        }
+    }
+}
+
+/// The log of the scene queries made through a [`RapierQueryPipeline`], enabled with
+/// [`RapierQueryPipeline::enable_query_log`].
+///
+/// The log is shared by the clones of the pipeline. It is usually drained by the debug-renderer,
+/// and keeps at most [`QueryLog::CAPACITY`] queries: the oldest queries are dropped when it is
+/// full.
+#[derive(Clone, Debug, Default)]
+pub struct QueryLog(Arc<Mutex<VecDeque<LoggedQuery>>>);
+
+impl QueryLog {
+    /// The maximum number of queries kept by the log.
+    pub const CAPACITY: usize = 4096;
+
+    pub(crate) fn push(&self, query: LoggedQuery) {
+        if let Ok(mut queries) = self.0.lock() {
+            if queries.len() >= Self::CAPACITY {
+                queries.pop_front();
+            }
+            queries.push_back(query);
+        }
+    }
+
+    /// Removes and returns the queries logged since the last call to this method, from the
+    /// oldest to the most recent one.
+    pub fn drain(&self) -> Vec<LoggedQuery> {
+        self.0
+            .lock()
+            .map(|mut queries| queries.drain(..).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use bevy::time::TimePlugin;
+
+    use super::*;
+    use crate::plugin::context::systemparams::ReadRapierContext;
+    use crate::plugin::context::RapierQueryPipeline;
+    use crate::prelude::*;
+
+    #[test]
+    fn scene_queries_are_logged() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.finish();
+        let ball = app
+            .world_mut()
+            .spawn((Transform::from_xyz(5.0, 0.0, 0.0), Collider::ball(1.0)))
+            .id();
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        let cast = |world: &mut World| {
+            world
+                .run_system_once(|context: ReadRapierContext| {
+                    let context = context.single().unwrap();
+                    let filter = QueryFilter::default();
+                    context.cast_ray(Vect::ZERO, Vect::X, 10.0, true, filter);
+                    context.cast_ray_and_get_normal(Vect::ZERO, -Vect::X, 10.0, true, filter);
+                    context.intersections_with_shape(
+                        Vect::X * 4.0,
+                        Rot::default(),
+                        &Collider::ball(0.5),
+                        filter,
+                        |_| true,
+                    );
+                })
+                .unwrap();
+        };
+        let mut query_pipeline = world.query::<&mut RapierQueryPipeline>();
+        cast(world);
+        assert!(query_pipeline.single(world).unwrap().query_log().is_none());
+
+        query_pipeline.single_mut(world).unwrap().enable_query_log();
+        cast(world);
+        let logged = query_pipeline
+            .single(world)
+            .unwrap()
+            .query_log()
+            .unwrap()
+            .drain();
+        assert_eq!(logged.len(), 3);
+        let LoggedQuery::Ray { hit: Some(hit), .. } = &logged[0] else {
+            panic!("The first ray should hit the ball");
+        };
+        assert_eq!(hit.entity, ball);
+        assert!((hit.point - Vect::X * 4.0).length() < 1.0e-3);
+        assert!(matches!(logged[1], LoggedQuery::Ray { hit: None, .. }));
+        assert!(
+            matches!(&logged[2], LoggedQuery::ShapeIntersections { entities, .. } if entities == &[ball])
+        );
+        assert!(query_pipeline
+            .single(world)
+            .unwrap()
+            .query_log()
+            .unwrap()
+            .drain()
+            .is_empty());
+    }
+
+    #[test]
+    fn query_log_is_bounded() {
+        let log = QueryLog::default();
+        for i in 0..QueryLog::CAPACITY + 10 {
+            log.push(LoggedQuery::PointProjection {
+                point: Vect::X * i as f32,
+                hit: None,
+            });
+        }
+        let logged = log.drain();
+        assert_eq!(logged.len(), QueryLog::CAPACITY);
+        assert!(
+            matches!(logged[0], LoggedQuery::PointProjection { point, .. } if point == Vect::X * 10.0),
+            "The oldest queries should be dropped first"
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! Remapping of the entities referenced by a rapier context, to load it into another [`World`].
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use bevy::ecs::entity::{EntityHashMap, EntityMapper, MapEntities};
+use bevy::prelude::*;
+use rapier::prelude::{ColliderSet, RigidBodySet};
+
+use super::systemparams::RAPIER_CONTEXT_EXPECT_ERROR;
+use super::{
+    RapierContextColliders, RapierContextEntityLink, RapierContextJoints, RapierContextSimulation,
+    RapierRigidBodySet, RapierSnapshot,
+};
+use crate::dynamics::{
+    RapierImpulseJointHandle, RapierMultibodyJointHandle, RapierRigidBodyHandle,
+};
+use crate::geometry::RapierColliderHandle;
+
+fn map_entity_bits<E: EntityMapper>(user_data: &mut u128, entity_mapper: &mut E) {
+    let entity = Entity::from_bits(*user_data as u64);
+    *user_data = entity_mapper.get_mapped(entity).to_bits() as u128;
+}
+
+fn map_keys<H, E: EntityMapper>(map: &mut HashMap<Entity, H>, entity_mapper: &mut E) {
+    *map = map
+        .drain()
+        .map(|(entity, handle)| (entity_mapper.get_mapped(entity), handle))
+        .collect();
+}
+
+fn map_values<H: Eq + Hash, E: EntityMapper>(map: &mut HashMap<H, Entity>, entity_mapper: &mut E) {
+    for entity in map.values_mut() {
+        *entity = entity_mapper.get_mapped(*entity);
+    }
+}
+
+fn map_collider_set<E: EntityMapper>(colliders: &mut ColliderSet, entity_mapper: &mut E) {
+    for (_, collider) in colliders.iter_mut() {
+        map_entity_bits(&mut collider.user_data, entity_mapper);
+    }
+}
+
+fn map_rigid_body_set<E: EntityMapper>(bodies: &mut RigidBodySet, entity_mapper: &mut E) {
+    for (_, body) in bodies.iter_mut() {
+        map_entity_bits(&mut body.user_data, entity_mapper);
+    }
+}
+
+impl MapEntities for RapierContextColliders {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        map_collider_set(&mut self.colliders, entity_mapper);
+        map_keys(&mut self.entity2collider, entity_mapper);
+    }
+}
+
+impl MapEntities for RapierRigidBodySet {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        map_rigid_body_set(&mut self.bodies, entity_mapper);
+        map_keys(&mut self.entity2body, entity_mapper);
+    }
+}
+
+impl MapEntities for RapierContextJoints {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        map_keys(&mut self.entity2impulse_joint, entity_mapper);
+        map_keys(&mut self.entity2multibody_joint, entity_mapper);
+    }
+}
+
+impl MapEntities for RapierContextSimulation {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        map_values(&mut self.deleted_colliders, entity_mapper);
+    }
+}
+
+impl MapEntities for RapierSnapshot {
+    fn map_entities<E: EntityMapper>(&mut self, entity_mapper: &mut E) {
+        map_values(&mut self.deleted_colliders, entity_mapper);
+        map_collider_set(&mut self.colliders, entity_mapper);
+        map_keys(&mut self.entity2collider, entity_mapper);
+        map_keys(&mut self.entity2impulse_joint, entity_mapper);
+        map_keys(&mut self.entity2multibody_joint, entity_mapper);
+        map_rigid_body_set(&mut self.bodies, entity_mapper);
+        map_keys(&mut self.entity2body, entity_mapper);
+    }
+}
+
+/// A [`Command`] remapping the entities referenced by a rapier context, after it was loaded
+/// into a [`World`] where the entity IDs differ from the ones it was saved from.
+///
+/// This is typically used after deserializing the context components (with the `serde-serialize`
+/// feature) or restoring a [`RapierSnapshot`] taken in another world, for save games or level
+/// streaming:
+/// - the entities stored in the `user_data` of rapier rigid-bodies and colliders, and the entity
+///   maps of the context are rewritten through `entity_map`;
+/// - the handle components ([`RapierRigidBodyHandle`], [`RapierColliderHandle`],
+///   [`RapierImpulseJointHandle`] and [`RapierMultibodyJointHandle`]) and the
+///   [`RapierContextEntityLink`] are inserted on the mapped entities, so that `bevy_rapier`
+///   doesn't create new rapier objects for them.
+///
+/// Entities missing from `entity_map` are left untouched. The mapped entities are expected to
+/// carry the same `bevy_rapier` components (e.g. [`RigidBody`](crate::prelude::RigidBody) or
+/// [`Collider`](crate::prelude::Collider)) as the ones they were saved from.
+pub struct RemapRapierContext {
+    /// The entity holding the [`RapierContextSimulation`] and its related components.
+    pub context: Entity,
+    /// The map from the saved entities to the entities of the current world.
+    pub entity_map: EntityHashMap<Entity>,
+}
+
+impl Command for RemapRapierContext {
+    fn apply(mut self, world: &mut World) {
+        let mut context = world.query::<(
+            &mut RapierContextSimulation,
+            &mut RapierContextColliders,
+            &mut RapierContextJoints,
+            &mut RapierRigidBodySet,
+        )>();
+        let Ok((mut simulation, mut colliders, mut joints, mut bodies)) =
+            context.get_mut(world, self.context)
+        else {
+            log::error!(
+                "Could not remap the rapier context {}: {}",
+                self.context,
+                RAPIER_CONTEXT_EXPECT_ERROR
+            );
+            return;
+        };
+
+        simulation.map_entities(&mut self.entity_map);
+        colliders.map_entities(&mut self.entity_map);
+        joints.map_entities(&mut self.entity_map);
+        bodies.map_entities(&mut self.entity_map);
+
+        let body_handles: Vec<_> = bodies
+            .entity2body()
+            .iter()
+            .map(|(entity, handle)| (*entity, RapierRigidBodyHandle(*handle)))
+            .collect();
+        let collider_handles: Vec<_> = colliders
+            .entity2collider()
+            .iter()
+            .map(|(entity, handle)| (*entity, RapierColliderHandle(*handle)))
+            .collect();
+        let impulse_joint_handles: Vec<_> = joints
+            .entity2impulse_joint()
+            .iter()
+            .map(|(entity, handle)| (*entity, RapierImpulseJointHandle(*handle)))
+            .collect();
+        let multibody_joint_handles: Vec<_> = joints
+            .entity2multibody_joint()
+            .iter()
+            .map(|(entity, handle)| (*entity, RapierMultibodyJointHandle(*handle)))
+            .collect();
+
+        let link = RapierContextEntityLink(self.context);
+        insert_handles(world, body_handles, link);
+        insert_handles(world, collider_handles, link);
+        insert_handles(world, impulse_joint_handles, link);
+        insert_handles(world, multibody_joint_handles, link);
+    }
+}
+
+fn insert_handles<H: Component>(
+    world: &mut World,
+    handles: Vec<(Entity, H)>,
+    link: RapierContextEntityLink,
+) {
+    for (entity, handle) in handles {
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.insert((handle, link));
+        } else {
+            log::warn!("Could not insert the rapier handle of {entity}: the entity doesn't exist.");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::time::TimePlugin;
+
+    use super::*;
+    use crate::plugin::context::systemparams::WriteRapierContext;
+    use crate::plugin::context::DefaultRapierContext;
+    use crate::prelude::*;
+
+    fn new_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.finish();
+        // Initialize the default context.
+        app.update();
+        app
+    }
+
+    fn spawn_bodies(world: &mut World) -> Vec<Entity> {
+        (0..3)
+            .map(|i| {
+                world
+                    .spawn((
+                        Transform::from_xyz(i as f32 * 3.0, 1.0, 0.0),
+                        RigidBody::Dynamic,
+                        Collider::ball(0.5),
+                    ))
+                    .id()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn remapped_context_refers_to_new_entities() {
+        let mut saved_app = new_app();
+        let saved_entities = spawn_bodies(saved_app.world_mut());
+        for _ in 0..5 {
+            saved_app.update();
+        }
+        let snapshot = saved_app
+            .world_mut()
+            .run_system_once(|context: WriteRapierContext| context.take_snapshot().unwrap())
+            .unwrap();
+
+        let mut loaded_app = new_app();
+        // Offset the entity IDs of the loading world.
+        for _ in 0..10 {
+            loaded_app.world_mut().spawn_empty();
+        }
+        let loaded_entities = spawn_bodies(loaded_app.world_mut());
+        let entity_map: EntityHashMap<Entity> = saved_entities
+            .iter()
+            .copied()
+            .zip(loaded_entities.iter().copied())
+            .collect();
+        assert!(saved_entities.iter().all(|e| !loaded_entities.contains(e)));
+
+        let world = loaded_app.world_mut();
+        world
+            .run_system_once(move |mut context: WriteRapierContext| {
+                context.restore_snapshot(&snapshot).unwrap()
+            })
+            .unwrap();
+        let context = world
+            .query_filtered::<Entity, With<DefaultRapierContext>>()
+            .single(world)
+            .unwrap();
+        RemapRapierContext {
+            context,
+            entity_map,
+        }
+        .apply(world);
+        loaded_app.update();
+
+        let world = loaded_app.world_mut();
+        let (bodies, colliders) = world
+            .query::<(&RapierRigidBodySet, &RapierContextColliders)>()
+            .single(world)
+            .unwrap();
+        assert_eq!(
+            bodies.bodies.len(),
+            3,
+            "No rigid-body should be created for the remapped entities"
+        );
+        assert_eq!(colliders.colliders.len(), 3);
+        for entity in &loaded_entities {
+            let handle = world.get::<RapierRigidBodyHandle>(*entity).unwrap().0;
+            assert_eq!(bodies.entity2body()[entity], handle);
+            assert_eq!(bodies.rigid_body_entity(handle), Some(*entity));
+            let collider = world.get::<RapierColliderHandle>(*entity).unwrap().0;
+            assert_eq!(colliders.collider_entity(collider), Some(*entity));
+            assert_eq!(
+                world.get::<RapierContextEntityLink>(*entity),
+                Some(&RapierContextEntityLink(context))
+            );
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Save and restore the whole state of a rapier context.
+
+use std::collections::HashMap;
+
+use bevy::prelude::{Entity, GlobalTransform};
+use rapier::geometry::DefaultBroadPhase;
+use rapier::prelude::{
+    CCDSolver, ColliderHandle, ColliderSet, ImpulseJointHandle, ImpulseJointSet,
+    IntegrationParameters, IslandManager, MultibodyJointHandle, MultibodyJointSet, NarrowPhase,
+    QueryPipeline, RigidBodyHandle, RigidBodySet,
+};
+
+use super::{
+    RapierContextColliders, RapierContextJoints, RapierContextSimulation, RapierQueryPipeline,
+    RapierRigidBodySet, SimulationToRenderTime,
+};
+
+#[cfg(doc)]
+use crate::prelude::{Sleeping, Velocity, WriteRapierContext};
+#[cfg(doc)]
+use bevy::prelude::Transform;
+
+/// A copy of the complete state of a rapier context, which can be restored later.
+///
+/// Unlike serializing the context components directly, a snapshot also contains the
+/// private bookkeeping of `bevy_rapier` (the entity-to-handle maps, the colliders deleted
+/// since the last step, and the transforms used for change detection), as well as the
+/// [`SimulationToRenderTime`]. Restoring a snapshot then stepping the simulation yields
+/// bit-identical results to stepping from the moment the snapshot was taken, which makes it
+/// suitable for rollback networking, deterministic replays or editor undo.
+///
+/// The [`PhysicsPipeline`](rapier::prelude::PhysicsPipeline) isn't part of the snapshot: it
+/// only contains scratch buffers and counters which are reset at each step.
+///
+/// Use [`WriteRapierContext::take_snapshot`] and [`WriteRapierContext::restore_snapshot`]
+/// to save and restore a context from a system.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct RapierSnapshot {
+    pub(super) islands: IslandManager,
+    pub(super) broad_phase: DefaultBroadPhase,
+    pub(super) narrow_phase: NarrowPhase,
+    pub(super) ccd_solver: CCDSolver,
+    pub(super) integration_parameters: IntegrationParameters,
+    pub(super) deleted_colliders: HashMap<ColliderHandle, Entity>,
+    pub(super) colliders: ColliderSet,
+    pub(super) entity2collider: HashMap<Entity, ColliderHandle>,
+    pub(super) impulse_joints: ImpulseJointSet,
+    pub(super) multibody_joints: MultibodyJointSet,
+    pub(super) entity2impulse_joint: HashMap<Entity, ImpulseJointHandle>,
+    pub(super) entity2multibody_joint: HashMap<Entity, MultibodyJointHandle>,
+    pub(super) query_pipeline: QueryPipeline,
+    pub(super) bodies: RigidBodySet,
+    pub(super) entity2body: HashMap<Entity, RigidBodyHandle>,
+    pub(super) last_body_transform_set: HashMap<RigidBodyHandle, GlobalTransform>,
+    pub(super) sim_to_render_time: f32,
+}
+
+impl RapierSnapshot {
+    /// Copies the state of the rapier context made of the given components.
+    pub fn take(
+        simulation: &RapierContextSimulation,
+        colliders: &RapierContextColliders,
+        joints: &RapierContextJoints,
+        query_pipeline: &RapierQueryPipeline,
+        rigidbody_set: &RapierRigidBodySet,
+        sim_to_render_time: &SimulationToRenderTime,
+    ) -> Self {
+        Self {
+            islands: simulation.islands.clone(),
+            broad_phase: simulation.broad_phase.clone(),
+            narrow_phase: simulation.narrow_phase.clone(),
+            ccd_solver: simulation.ccd_solver.clone(),
+            integration_parameters: simulation.integration_parameters,
+            deleted_colliders: simulation.deleted_colliders.clone(),
+            colliders: colliders.colliders.clone(),
+            entity2collider: colliders.entity2collider.clone(),
+            impulse_joints: joints.impulse_joints.clone(),
+            multibody_joints: joints.multibody_joints.clone(),
+            entity2impulse_joint: joints.entity2impulse_joint.clone(),
+            entity2multibody_joint: joints.entity2multibody_joint.clone(),
+            query_pipeline: query_pipeline.query_pipeline.clone(),
+            bodies: rigidbody_set.bodies.clone(),
+            entity2body: rigidbody_set.entity2body.clone(),
+            last_body_transform_set: rigidbody_set.last_body_transform_set.clone(),
+            sim_to_render_time: sim_to_render_time.diff,
+        }
+    }
+
+    /// Overwrites the state of the rapier context made of the given components with this snapshot.
+    ///
+    /// The [`Transform`], [`Velocity`] and [`Sleeping`] components of the restored rigid-bodies
+    /// are re-synchronized with the restored state during the next [`PhysicsSet::SyncBackend`](crate::plugin::PhysicsSet::SyncBackend),
+    /// before any user change is applied to the simulation. The handle components of the entities
+    /// which weren't part of this snapshot are removed at the same time, so that new rapier objects
+    /// are created for them.
+    pub fn restore(
+        &self,
+        simulation: &mut RapierContextSimulation,
+        colliders: &mut RapierContextColliders,
+        joints: &mut RapierContextJoints,
+        query_pipeline: &mut RapierQueryPipeline,
+        rigidbody_set: &mut RapierRigidBodySet,
+        sim_to_render_time: &mut SimulationToRenderTime,
+    ) {
+        simulation.islands.clone_from(&self.islands);
+        simulation.broad_phase.clone_from(&self.broad_phase);
+        simulation.narrow_phase.clone_from(&self.narrow_phase);
+        simulation.ccd_solver.clone_from(&self.ccd_solver);
+        simulation.integration_parameters = self.integration_parameters;
+        simulation
+            .deleted_colliders
+            .clone_from(&self.deleted_colliders);
+        // Events of the previous step were computed from a state that doesn't exist anymore.
+        simulation.collision_events_to_send.clear();
+        simulation.collision_details_to_send.clear();
+        simulation.contact_force_events_to_send.clear();
+        simulation.impact_events_to_send.clear();
+        simulation.impacting_pairs.clear();
+
+        colliders.colliders.clone_from(&self.colliders);
+        colliders.entity2collider.clone_from(&self.entity2collider);
+
+        joints.impulse_joints.clone_from(&self.impulse_joints);
+        joints.multibody_joints.clone_from(&self.multibody_joints);
+        joints
+            .entity2impulse_joint
+            .clone_from(&self.entity2impulse_joint);
+        joints
+            .entity2multibody_joint
+            .clone_from(&self.entity2multibody_joint);
+
+        query_pipeline
+            .query_pipeline
+            .clone_from(&self.query_pipeline);
+
+        rigidbody_set.bodies.clone_from(&self.bodies);
+        rigidbody_set.entity2body.clone_from(&self.entity2body);
+        rigidbody_set
+            .last_body_transform_set
+            .clone_from(&self.last_body_transform_set);
+        rigidbody_set.restored_from_snapshot = true;
+
+        sim_to_render_time.diff = self.sim_to_render_time;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::{
+        ecs::system::RunSystemOnce,
+        prelude::*,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+
+    use crate::math::Real;
+    use crate::plugin::context::systemparams::WriteRapierContext;
+    use crate::prelude::*;
+
+    #[cfg(feature = "dim3")]
+    fn cuboid(hx: Real, hy: Real, hz: Real) -> Collider {
+        Collider::cuboid(hx, hy, hz)
+    }
+    #[cfg(feature = "dim2")]
+    fn cuboid(hx: Real, hy: Real, _hz: Real) -> Collider {
+        Collider::cuboid(hx, hy)
+    }
+
+    fn body_states(app: &mut App) -> Vec<(Entity, Transform, Velocity)> {
+        let world = app.world_mut();
+        let mut states: Vec<_> = world
+            .query_filtered::<(Entity, &Transform, &Velocity), With<RigidBody>>()
+            .iter(world)
+            .map(|(e, t, v)| (e, *t, *v))
+            .collect();
+        states.sort_by_key(|(e, _, _)| *e);
+        states
+    }
+
+    #[test]
+    fn restored_snapshot_steps_identically() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1.0 / 60.0),
+        ));
+        app.finish();
+
+        app.world_mut().spawn((
+            Transform::default(),
+            RigidBody::Fixed,
+            cuboid(10.0, 0.5, 10.0),
+        ));
+        for i in 0..4 {
+            app.world_mut().spawn((
+                Transform::from_xyz(i as f32 * 0.3, 2.0 + i as f32 * 1.1, 0.0),
+                RigidBody::Dynamic,
+                Velocity::default(),
+                cuboid(0.5, 0.5, 0.5),
+            ));
+        }
+
+        for _ in 0..30 {
+            app.update();
+        }
+
+        let snapshot = app
+            .world_mut()
+            .run_system_once(|context: WriteRapierContext| context.take_snapshot().unwrap())
+            .unwrap();
+        let states_at_snapshot = body_states(&mut app);
+
+        let mut expected = Vec::new();
+        for _ in 0..60 {
+            app.update();
+            expected.push(body_states(&mut app));
+        }
+
+        app.world_mut()
+            .run_system_once(move |mut context: WriteRapierContext| {
+                context.restore_snapshot(&snapshot).unwrap()
+            })
+            .unwrap();
+
+        let mut restored = Vec::new();
+        for _ in 0..60 {
+            app.update();
+            restored.push(body_states(&mut app));
+        }
+
+        assert_ne!(
+            states_at_snapshot, expected[59],
+            "Bodies should have moved after the snapshot"
+        );
+        assert_eq!(
+            expected, restored,
+            "Stepping from a restored snapshot should be bit-identical"
+        );
+    }
+
+    #[test]
+    fn restored_snapshot_reinitializes_entities_spawned_after_it() {
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1.0 / 60.0),
+        ));
+        app.finish();
+        // Initialize the default context.
+        app.update();
+
+        let before = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                cuboid(0.5, 0.5, 0.5),
+            ))
+            .id();
+        app.update();
+
+        let snapshot = app
+            .world_mut()
+            .run_system_once(|context: WriteRapierContext| context.take_snapshot().unwrap())
+            .unwrap();
+
+        let after = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(3.0, 0.0, 0.0),
+                RigidBody::Dynamic,
+                cuboid(0.5, 0.5, 0.5),
+            ))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+        let stale_handle = app.world().get::<RapierRigidBodyHandle>(after).unwrap().0;
+
+        app.world_mut()
+            .run_system_once(move |mut context: WriteRapierContext| {
+                context.restore_snapshot(&snapshot).unwrap()
+            })
+            .unwrap();
+        app.update();
+
+        let world = app.world_mut();
+        let (bodies, colliders) = world
+            .query::<(&RapierRigidBodySet, &RapierContextColliders)>()
+            .single(world)
+            .unwrap();
+        assert_eq!(bodies.bodies.len(), 2);
+        assert_eq!(colliders.colliders.len(), 2);
+        for entity in [before, after] {
+            let handle = world.get::<RapierRigidBodyHandle>(entity).unwrap().0;
+            assert_eq!(bodies.rigid_body_entity(handle), Some(entity));
+            let collider = world.get::<RapierColliderHandle>(entity).unwrap().0;
+            assert_eq!(colliders.collider_entity(collider), Some(entity));
+            assert_eq!(colliders.colliders[collider].parent(), Some(handle));
+        }
+        assert_eq!(
+            bodies.rigid_body_entity(stale_handle),
+            Some(after),
+            "The body of the entity spawned after the snapshot should be created again"
+        );
+    }
+}
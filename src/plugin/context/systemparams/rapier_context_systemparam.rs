@@ -9,7 +9,7 @@ pub(crate) const RAPIER_CONTEXT_EXPECT_ERROR: &str =
 use crate::{
     plugin::context::{
         DefaultRapierContext, RapierContextColliders, RapierContextJoints, RapierContextSimulation,
-        RapierQueryPipeline, RapierRigidBodySet,
+        RapierQueryPipeline, RapierRigidBodySet, RapierSnapshot, SimulationToRenderTime,
     },
     prelude::QueryFilter,
 };
@@ -91,6 +91,9 @@ pub struct WriteRapierContext<'w, 's, T: query::QueryFilter + 'static = With<Def
         ),
         T,
     >,
+    /// The query used to save and restore the [`SimulationToRenderTime`] of the context along with
+    /// a [`RapierSnapshot`].
+    pub sim_to_render_time: Query<'w, 's, &'static mut SimulationToRenderTime, T>,
 }
 
 impl<'w, 's, T: query::QueryFilter + 'static> WriteRapierContext<'w, 's, T> {
@@ -127,6 +130,49 @@ impl<'w, 's, T: query::QueryFilter + 'static> WriteRapierContext<'w, 's, T> {
             rigidbody_set,
         })
     }
+
+    /// Copies the whole state of the single context corresponding to the filter (T) of [`WriteRapierContext`].
+    ///
+    /// If the number of query items is not exactly one, a [`bevy::ecs::query::QuerySingleError`] is returned instead.
+    ///
+    /// The [`SimulationToRenderTime`] component of the context entity is saved along with it.
+    ///
+    /// See [`RapierSnapshot`] for more details.
+    pub fn take_snapshot(&self) -> Result<RapierSnapshot> {
+        let (simulation, colliders, joints, query_pipeline, rigidbody_set) =
+            self.rapier_context.single()?;
+        Ok(RapierSnapshot::take(
+            simulation,
+            colliders,
+            joints,
+            query_pipeline,
+            rigidbody_set,
+            self.sim_to_render_time.single()?,
+        ))
+    }
+
+    /// Overwrites the whole state of the single context corresponding to the filter (T) of [`WriteRapierContext`]
+    /// with the given snapshot.
+    ///
+    /// If the number of query items is not exactly one, a [`bevy::ecs::query::QuerySingleError`] is returned instead.
+    ///
+    /// The [`SimulationToRenderTime`] component of the context entity is restored along with it.
+    ///
+    /// See [`RapierSnapshot`] for more details.
+    pub fn restore_snapshot(&mut self, snapshot: &RapierSnapshot) -> Result<()> {
+        let (mut simulation, mut colliders, mut joints, mut query_pipeline, mut rigidbody_set) =
+            self.rapier_context.single_mut()?;
+        let mut sim_to_render_time = self.sim_to_render_time.single_mut()?;
+        snapshot.restore(
+            &mut simulation,
+            &mut colliders,
+            &mut joints,
+            &mut query_pipeline,
+            &mut rigidbody_set,
+            &mut sim_to_render_time,
+        );
+        Ok(())
+    }
 }
 
 /// A helper struct to avoid passing too many parameters to most rapier functions.
@@ -1,12 +1,14 @@
 pub use self::configuration::{RapierConfiguration, TimestepMode};
 pub use self::context::{
     systemparams::{RapierContext, RapierContextMut, ReadRapierContext, WriteRapierContext},
-    DefaultRapierContext, RapierContextEntityLink, SimulationToRenderTime,
+    DefaultRapierContext, RapierContextEntityLink, RapierSnapshot, RemapRapierContext,
+    SimulationToRenderTime,
 };
 pub use self::plugin::{
     NoUserData, PhysicsSet, RapierBevyComponentApply, RapierContextInitialization,
     RapierPhysicsPlugin, RapierTransformPropagateSet,
 };
+pub use self::recording::{RapierRecorderPlugin, RapierReplayPlugin};
 pub use narrow_phase::{ContactManifoldView, ContactPairView, ContactView, SolverContactView};
 
 #[allow(clippy::type_complexity)]
@@ -18,3 +20,4 @@ pub mod context;
 mod narrow_phase;
 #[allow(clippy::module_inception)]
 mod plugin;
+pub mod recording;
@@ -1,6 +1,11 @@
-use crate::pipeline::{CollisionEvent, ContactForceEvent};
+use crate::pipeline::{
+    CollisionEvent, CollisionStartDetails, ContactForceEvent, ImpactEvent,
+    PropagateCollisionEvents, RapierContextEvent,
+};
 use crate::prelude::*;
 use crate::reflect::IntegrationParametersWrapper;
+#[cfg(feature = "serde-serialize")]
+use crate::reflect::QueryFilterFlagsWrapper;
 use bevy::ecs::{
     intern::Interned,
     schedule::{IntoScheduleConfigs, ScheduleConfigs, ScheduleLabel},
@@ -129,6 +134,10 @@ where
                     // A good candidate for required component or hook components.
                     // The configuration is needed for following systems, so it should be chained.
                     setup_rapier_configuration,
+                    // Re-sync components of contexts restored from a snapshot before
+                    // anything reads them.
+                    systems::sync_restored_handles,
+                    systems::writeback_restored_rigid_bodies,
                     // Run the character controller before the manual transform propagation.
                     systems::update_character_controls,
                 )
@@ -146,10 +155,19 @@ where
                         systems::on_add_entity_with_parent,
                         systems::on_change_context,
                         systems::sync_removals,
-                        #[cfg(all(feature = "dim3", feature = "async-collider"))]
+                        systems::init_collider_shapes,
+                        #[cfg(feature = "async-collider")]
+                        systems::invalidate_mesh_collider_cache,
+                        #[cfg(feature = "async-collider")]
                         systems::init_async_scene_colliders,
-                        #[cfg(all(feature = "dim3", feature = "async-collider"))]
+                        #[cfg(feature = "async-collider")]
                         systems::init_async_colliders,
+                        #[cfg(feature = "async-collider")]
+                        systems::update_colliders_from_meshes,
+                        #[cfg(feature = "async-collider")]
+                        systems::poll_async_collider_tasks,
+                        #[cfg(feature = "collider-asset")]
+                        systems::init_async_collider_assets,
                         systems::init_rigid_bodies,
                         systems::init_colliders,
                         systems::init_joints,
@@ -177,6 +195,7 @@ where
                 .into_configs(),
             PhysicsSet::Writeback => (
                 systems::update_colliding_entities,
+                systems::update_trigger_zones,
                 systems::writeback_rigid_bodies,
                 // Each writeback write to different properties.
                 systems::writeback_mass_properties.ambiguous_with(systems::writeback_rigid_bodies),
@@ -259,16 +278,35 @@ where
             .register_type::<CollisionGroups>()
             .register_type::<SolverGroups>()
             .register_type::<ContactForceEventThreshold>()
+            .register_type::<ImpactSpeedThreshold>()
+            .register_type::<TriggerZone>()
+            .register_type::<FilteredTriggerZone>()
             .register_type::<ContactSkin>()
+            .register_type::<ColliderShape>()
+            .register_type::<ImpulseJoint>()
+            .register_type::<MultibodyJoint>()
+            .register_type::<KinematicCharacterController>()
+            .register_type::<KinematicCharacterControllerOutput>()
+            .register_type::<TransformInterpolation>()
             .register_type::<Group>()
             .register_type::<RapierContextEntityLink>()
             .register_type::<RapierConfiguration>()
             .register_type::<SimulationToRenderTime>()
             .register_type::<DefaultRapierContext>()
-            .register_type::<RapierContextInitialization>();
+            .register_type::<RapierContextInitialization>()
+            .register_type::<PropagateCollisionEvents>();
+        // Opaque remote types can't derive their serialization type data.
+        #[cfg(feature = "serde-serialize")]
+        app.register_type::<QueryFilterFlagsWrapper>()
+            .register_type_data::<QueryFilterFlagsWrapper, ReflectSerialize>()
+            .register_type_data::<QueryFilterFlagsWrapper, ReflectDeserialize>();
 
         app.insert_resource(Events::<CollisionEvent>::default())
             .insert_resource(Events::<ContactForceEvent>::default())
+            .insert_resource(Events::<RapierContextEvent<CollisionEvent>>::default())
+            .insert_resource(Events::<RapierContextEvent<ContactForceEvent>>::default())
+            .insert_resource(Events::<CollisionStartDetails>::default())
+            .insert_resource(Events::<ImpactEvent>::default())
             .insert_resource(Events::<MassModifiedEvent>::default());
         let default_world_init = app.world().get_resource::<RapierContextInitialization>();
         if let Some(world_init) = default_world_init {
@@ -339,9 +377,16 @@ where
     }
 
     fn finish(&self, _app: &mut App) {
-        #[cfg(all(feature = "dim3", feature = "async-collider"))]
+        #[cfg(feature = "async-collider")]
         {
-            use bevy::{asset::AssetPlugin, render::mesh::MeshPlugin, scene::ScenePlugin};
+            use crate::geometry::{AsyncColliderStatus, MeshColliderCache};
+            use bevy::{
+                app::TaskPoolPlugin, asset::AssetPlugin, render::mesh::MeshPlugin,
+                scene::ScenePlugin,
+            };
+            if !_app.is_plugin_added::<TaskPoolPlugin>() {
+                _app.add_plugins(TaskPoolPlugin::default());
+            }
             if !_app.is_plugin_added::<AssetPlugin>() {
                 _app.add_plugins(AssetPlugin::default());
             }
@@ -351,6 +396,19 @@ where
             if !_app.is_plugin_added::<ScenePlugin>() {
                 _app.add_plugins(ScenePlugin);
             }
+            _app.init_resource::<MeshColliderCache>()
+                .register_type::<AsyncColliderStatus>();
+        }
+        #[cfg(feature = "collider-asset")]
+        {
+            use crate::geometry::{AsyncColliderAsset, ColliderAsset, ColliderAssetLoader};
+            use bevy::asset::AssetPlugin;
+            if !_app.is_plugin_added::<AssetPlugin>() {
+                _app.add_plugins(AssetPlugin::default());
+            }
+            _app.init_asset::<ColliderAsset>()
+                .init_asset_loader::<ColliderAssetLoader>()
+                .register_type::<AsyncColliderAsset>();
         }
     }
 }
@@ -665,6 +723,54 @@ mod test {
         }
     }
 
+    #[test]
+    fn collider_properties_removal_resets_rapier_collider() {
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1f32 / 60f32),
+        ));
+        app.finish();
+        app.update();
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                cuboid(0.5, 0.5, 0.5),
+                Friction::new(0.1),
+                Restitution::new(0.9),
+                CollisionGroups::new(Group::GROUP_1, Group::GROUP_2),
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<(Friction, Restitution, CollisionGroups)>();
+        app.update();
+
+        let context = app
+            .world_mut()
+            .query::<RapierContext>()
+            .single(app.world())
+            .unwrap();
+        let handle = context.colliders.entity2collider()[&entity];
+        let co = &context.colliders.colliders[handle];
+        assert_eq!(co.friction(), Friction::default().coefficient);
+        assert_eq!(co.restitution(), Restitution::default().coefficient);
+        assert_eq!(
+            co.collision_groups(),
+            rapier::geometry::InteractionGroups::all()
+        );
+    }
+
     #[test]
     fn parent_child() {
         return main();
@@ -0,0 +1,841 @@
+//! Recording and replay of the user changes applied to a physics simulation.
+//!
+//! [`RapierRecorderPlugin`] logs, for each run of the physics schedule, the [`TimestepMode`],
+//! the time delta seen by [`RapierContextSimulation::step_simulation`] and every change made
+//! by the user to the components which `bevy_rapier` pushes into Rapier (rigid-body,
+//! collider and joint components, as well as the character controller translations),
+//! including their removal.
+//!
+//! The resulting [`PhysicsRecording`] can be serialized with the `serde-serialize` feature,
+//! and fed to [`RapierReplayPlugin`] in a fresh [`App`] to reproduce the simulation, for
+//! example to debug a physics issue headlessly.
+//!
+//! The replay app must spawn the same initial scene as the recorded app, in the same order,
+//! so that the recorded entities match: only changes made to entities already known to
+//! Rapier are recorded, not the spawning of new entities.
+//!
+//! To reproduce a recording on another platform, enable the `enhanced-determinism` feature
+//! on both the recording and replaying builds.
+
+use std::time::Duration;
+
+use bevy::ecs::{
+    intern::Interned,
+    query::QueryFilter,
+    schedule::{IntoScheduleConfigs, ScheduleLabel},
+};
+use bevy::prelude::*;
+
+use crate::control::KinematicCharacterController;
+use crate::dynamics::{
+    AdditionalMassProperties, Ccd, Damping, Dominance, ExternalForce, ExternalImpulse,
+    GenericJoint, GravityScale, ImpulseJoint, LockedAxes, MultibodyJoint, RapierImpulseJointHandle,
+    RapierMultibodyJointHandle, RapierRigidBodyHandle, RigidBody, RigidBodyDisabled, Sleeping,
+    TypedJoint, Velocity,
+};
+use crate::geometry::{
+    ActiveEvents, Collider, ColliderDisabled, ColliderMassProperties, CollisionGroups, Friction,
+    RapierColliderHandle, Restitution, Sensor, SolverGroups,
+};
+use crate::math::Vect;
+use crate::plugin::context::{RapierContextEntityLink, RapierRigidBodySet, SimulationToRenderTime};
+use crate::plugin::{systems, PhysicsSet, RapierBevyComponentApply, RapierConfiguration};
+use crate::plugin::{RapierTransformPropagateSet, TimestepMode};
+
+#[cfg(doc)]
+use crate::plugin::context::RapierContextSimulation;
+
+/// A change made by the user to a component of an entity managed by Rapier.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub enum RecordedChange {
+    /// The [`RigidBody`] type was changed.
+    RigidBody(RigidBody),
+    /// The [`Transform`] of a rigid-body was changed.
+    Transform(Transform),
+    /// The [`Velocity`] of a rigid-body was changed.
+    Velocity(Velocity),
+    /// The [`AdditionalMassProperties`] of a rigid-body were changed.
+    AdditionalMassProperties(AdditionalMassProperties),
+    /// The [`LockedAxes`] of a rigid-body were changed.
+    LockedAxes(LockedAxes),
+    /// The [`ExternalForce`] of a rigid-body was changed.
+    ExternalForce(ExternalForce),
+    /// An [`ExternalImpulse`] was applied to a rigid-body.
+    ExternalImpulse(ExternalImpulse),
+    /// The [`GravityScale`] of a rigid-body was changed.
+    GravityScale(GravityScale),
+    /// The [`Ccd`] of a rigid-body was changed.
+    Ccd(Ccd),
+    /// The [`Dominance`] of a rigid-body was changed.
+    Dominance(Dominance),
+    /// The [`Sleeping`] state of a rigid-body was changed.
+    Sleeping(Sleeping),
+    /// The [`Damping`] of a rigid-body was changed.
+    Damping(Damping),
+    /// A [`RigidBodyDisabled`] component was added.
+    RigidBodyDisabled,
+    /// The shape of a [`Collider`] was changed.
+    Collider(Collider),
+    /// The [`Friction`] of a collider was changed.
+    Friction(Friction),
+    /// The [`Restitution`] of a collider was changed.
+    Restitution(Restitution),
+    /// The [`CollisionGroups`] of a collider were changed.
+    CollisionGroups(CollisionGroups),
+    /// The [`SolverGroups`] of a collider were changed.
+    SolverGroups(SolverGroups),
+    /// The [`ColliderMassProperties`] of a collider were changed.
+    ColliderMassProperties(ColliderMassProperties),
+    /// The [`ActiveEvents`] of a collider were changed.
+    ActiveEvents(ActiveEvents),
+    /// A [`Sensor`] component was added.
+    Sensor,
+    /// A [`ColliderDisabled`] component was added.
+    ColliderDisabled,
+    /// An [`ImpulseJoint`] was changed.
+    ImpulseJoint {
+        /// See [`ImpulseJoint::parent`].
+        parent: Entity,
+        /// The joint description, see [`ImpulseJoint::data`].
+        data: GenericJoint,
+    },
+    /// A [`MultibodyJoint`] was changed.
+    MultibodyJoint {
+        /// See [`MultibodyJoint::parent`].
+        parent: Entity,
+        /// The joint description, see [`MultibodyJoint::data`].
+        data: GenericJoint,
+    },
+    /// A translation was requested through [`KinematicCharacterController::translation`].
+    CharacterTranslation(Vect),
+    /// A component was removed, or its entity was despawned.
+    Removed(RecordedComponent),
+}
+
+/// A component whose removal changes the simulation, recorded by [`RecordedChange::Removed`].
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecordedComponent {
+    /// The [`RigidBody`] of a rigid-body.
+    RigidBody,
+    /// The [`RigidBodyDisabled`] marker of a rigid-body.
+    RigidBodyDisabled,
+    /// The [`Collider`] of a collider.
+    Collider,
+    /// The [`Sensor`] marker of a collider.
+    Sensor,
+    /// The [`ColliderDisabled`] marker of a collider.
+    ColliderDisabled,
+    /// The [`ImpulseJoint`] of a joint.
+    ImpulseJoint,
+    /// The [`MultibodyJoint`] of a joint.
+    MultibodyJoint,
+}
+
+/// Everything needed to reproduce one run of the physics schedule.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct RecordedStep {
+    /// The timestep mode used for this step.
+    pub timestep_mode: TimestepMode,
+    /// The time delta given to [`RapierContextSimulation::step_simulation`].
+    pub delta: Duration,
+    /// The changes applied by the user before this step, in the order they were recorded.
+    pub changes: Vec<(Entity, RecordedChange)>,
+}
+
+/// The log of a physics session, made of one [`RecordedStep`] per run of the physics schedule.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Default)]
+pub struct PhysicsRecording {
+    /// The recorded steps, in order.
+    pub steps: Vec<RecordedStep>,
+}
+
+/// Resource controlling the recording of a physics session, see [`RapierRecorderPlugin`].
+#[derive(Resource, Default)]
+pub struct PhysicsRecorder {
+    /// Is the recorder currently recording?
+    pub active: bool,
+    /// The steps recorded so far.
+    pub recording: PhysicsRecording,
+}
+
+impl PhysicsRecorder {
+    /// Returns the steps recorded so far, and starts a new recording.
+    pub fn take_recording(&mut self) -> PhysicsRecording {
+        std::mem::take(&mut self.recording)
+    }
+}
+
+/// Resource driving the replay of a [`PhysicsRecording`], see [`RapierReplayPlugin`].
+#[derive(Resource)]
+pub struct PhysicsReplay {
+    /// The recording being replayed.
+    pub recording: PhysicsRecording,
+    /// The index of the next step to be replayed.
+    pub next_step: usize,
+    /// The timestep mode of the app before the replay started, restored once it is finished.
+    timestep_mode: Option<TimestepMode>,
+}
+
+impl PhysicsReplay {
+    /// Starts replaying the given recording from its first step.
+    pub fn new(recording: PhysicsRecording) -> Self {
+        Self {
+            recording,
+            next_step: 0,
+            timestep_mode: None,
+        }
+    }
+
+    /// Returns `true` once every recorded step was replayed.
+    pub fn is_finished(&self) -> bool {
+        self.next_step >= self.recording.steps.len()
+    }
+}
+
+/// A plugin recording the user changes applied to the physics simulation into a [`PhysicsRecorder`].
+///
+/// This must be added after [`RapierPhysicsPlugin`](crate::plugin::RapierPhysicsPlugin), with the same schedule.
+pub struct RapierRecorderPlugin {
+    schedule: Interned<dyn ScheduleLabel>,
+}
+
+impl RapierRecorderPlugin {
+    /// Records the physics systems running in the provided schedule rather than `PostUpdate`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl Default for RapierRecorderPlugin {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+        }
+    }
+}
+
+impl Plugin for RapierRecorderPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PhysicsRecorder {
+            active: true,
+            ..default()
+        })
+        .add_systems(
+            self.schedule,
+            (
+                record_character_controls
+                    .in_set(PhysicsSet::SyncBackend)
+                    .before(systems::update_character_controls),
+                record_user_changes
+                    .in_set(PhysicsSet::SyncBackend)
+                    .after(RapierTransformPropagateSet)
+                    .after(systems::init_joints)
+                    .before(RapierBevyComponentApply),
+            ),
+        );
+    }
+}
+
+/// A plugin replaying a [`PhysicsRecording`], one recorded step for each run of the physics schedule.
+///
+/// The [`TimestepMode`] is overwritten so that each step advances the simulation by its recorded
+/// time delta, so the replay doesn't depend on the frame rate of the replaying app, and restored
+/// once the replay is finished. The [`Time`] of the replaying app is left untouched. Replays of
+/// [`TimestepMode::Interpolated`] recordings may slightly differ due to floating point rounding,
+/// and leave the [`SimulationToRenderTime`] of the contexts where the recording ended.
+///
+/// This must be added after [`RapierPhysicsPlugin`](crate::plugin::RapierPhysicsPlugin), with the same schedule.
+pub struct RapierReplayPlugin {
+    schedule: Interned<dyn ScheduleLabel>,
+    recording: PhysicsRecording,
+}
+
+impl RapierReplayPlugin {
+    /// Replays the given recording.
+    pub fn new(recording: PhysicsRecording) -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            recording,
+        }
+    }
+
+    /// Replays the physics systems running in the provided schedule rather than `PostUpdate`.
+    pub fn in_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl Plugin for RapierReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PhysicsReplay::new(self.recording.clone()))
+            .add_systems(
+                self.schedule,
+                (
+                    replay_recorded_step
+                        .in_set(PhysicsSet::SyncBackend)
+                        .before(systems::update_character_controls),
+                    finish_replay.in_set(PhysicsSet::Writeback),
+                ),
+            );
+    }
+}
+
+/// System starting a new [`RecordedStep`], with the translations requested by character controllers.
+pub fn record_character_controls(
+    mut recorder: ResMut<PhysicsRecorder>,
+    timestep_mode: Res<TimestepMode>,
+    time: Res<Time>,
+    controllers: Query<(Entity, &KinematicCharacterController)>,
+) {
+    if !recorder.active {
+        return;
+    }
+
+    let changes = controllers
+        .iter()
+        .filter_map(|(entity, controller)| {
+            controller
+                .translation
+                .map(|translation| (entity, RecordedChange::CharacterTranslation(translation)))
+        })
+        .collect();
+
+    recorder.recording.steps.push(RecordedStep {
+        timestep_mode: *timestep_mode,
+        delta: time.delta(),
+        changes,
+    });
+}
+
+/// The changes of the component `C` since the last run of the recording system.
+type Changes<'w, 's, C, F> = Query<'w, 's, (Entity, &'static C), (Changed<C>, F)>;
+
+fn record_changes<C: Component + Clone, F: QueryFilter>(
+    changes: &mut Vec<(Entity, RecordedChange)>,
+    query: &Changes<C, F>,
+    record: impl Fn(C) -> Option<RecordedChange>,
+) {
+    changes.extend(
+        query
+            .iter()
+            .filter_map(|(entity, component)| Some((entity, record(component.clone())?))),
+    );
+}
+
+/// System recording the changes made by the user to the components of rigid-bodies, colliders
+/// and joints, into the [`RecordedStep`] started by [`record_character_controls`].
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn record_user_changes(
+    mut recorder: ResMut<PhysicsRecorder>,
+    rigid_body_sets: Query<&RapierRigidBodySet>,
+    config: Query<&RapierConfiguration>,
+    changed_transforms: Query<
+        (
+            Entity,
+            &RapierRigidBodyHandle,
+            &RapierContextEntityLink,
+            &GlobalTransform,
+            &Transform,
+            Option<&KinematicCharacterController>,
+        ),
+        Changed<GlobalTransform>,
+    >,
+    changed_states: (
+        Query<
+            (
+                Entity,
+                &RapierRigidBodyHandle,
+                &RapierContextEntityLink,
+                &Velocity,
+            ),
+            Changed<Velocity>,
+        >,
+        Query<
+            (
+                Entity,
+                &RapierRigidBodyHandle,
+                &RapierContextEntityLink,
+                &Sleeping,
+            ),
+            Changed<Sleeping>,
+        >,
+    ),
+    changed_bodies: (
+        Changes<RigidBody, With<RapierRigidBodyHandle>>,
+        Changes<AdditionalMassProperties, With<RapierRigidBodyHandle>>,
+        Changes<LockedAxes, With<RapierRigidBodyHandle>>,
+        Changes<ExternalForce, With<RapierRigidBodyHandle>>,
+        Changes<ExternalImpulse, With<RapierRigidBodyHandle>>,
+        Changes<GravityScale, With<RapierRigidBodyHandle>>,
+        Changes<Ccd, With<RapierRigidBodyHandle>>,
+        Changes<Dominance, With<RapierRigidBodyHandle>>,
+        Changes<Damping, With<RapierRigidBodyHandle>>,
+        Changes<RigidBodyDisabled, With<RapierRigidBodyHandle>>,
+    ),
+    changed_colliders: (
+        Changes<Collider, With<RapierColliderHandle>>,
+        Changes<Friction, With<RapierColliderHandle>>,
+        Changes<Restitution, With<RapierColliderHandle>>,
+        Changes<CollisionGroups, With<RapierColliderHandle>>,
+        Changes<SolverGroups, With<RapierColliderHandle>>,
+        Changes<ColliderMassProperties, With<RapierColliderHandle>>,
+        Changes<ActiveEvents, With<RapierColliderHandle>>,
+        Changes<Sensor, With<RapierColliderHandle>>,
+        Changes<ColliderDisabled, With<RapierColliderHandle>>,
+    ),
+    changed_joints: (
+        Changes<ImpulseJoint, With<RapierImpulseJointHandle>>,
+        Changes<MultibodyJoint, With<RapierMultibodyJointHandle>>,
+    ),
+    removed: (
+        RemovedComponents<RigidBody>,
+        RemovedComponents<RigidBodyDisabled>,
+        RemovedComponents<Collider>,
+        RemovedComponents<Sensor>,
+        RemovedComponents<ColliderDisabled>,
+        RemovedComponents<ImpulseJoint>,
+        RemovedComponents<MultibodyJoint>,
+    ),
+) {
+    if !recorder.active {
+        return;
+    }
+    let Some(step) = recorder.recording.steps.last_mut() else {
+        return;
+    };
+    let changes = &mut step.changes;
+
+    // The removals are recorded first, so that a component removed and inserted again before
+    // this step is replayed in the same order.
+    let (
+        mut rb,
+        mut rb_disabled,
+        mut shapes,
+        mut sensors,
+        mut co_disabled,
+        mut impulse_joints,
+        mut multibody_joints,
+    ) = removed;
+    for (removed, component) in [
+        (rb.read().collect::<Vec<_>>(), RecordedComponent::RigidBody),
+        (
+            rb_disabled.read().collect(),
+            RecordedComponent::RigidBodyDisabled,
+        ),
+        (shapes.read().collect(), RecordedComponent::Collider),
+        (sensors.read().collect(), RecordedComponent::Sensor),
+        (
+            co_disabled.read().collect(),
+            RecordedComponent::ColliderDisabled,
+        ),
+        (
+            impulse_joints.read().collect(),
+            RecordedComponent::ImpulseJoint,
+        ),
+        (
+            multibody_joints.read().collect(),
+            RecordedComponent::MultibodyJoint,
+        ),
+    ] {
+        changes.extend(
+            removed
+                .into_iter()
+                .map(|entity| (entity, RecordedChange::Removed(component))),
+        );
+    }
+
+    let (rb, mprops, locked, force, impulse, gravity, ccd, dominance, damping, disabled) =
+        changed_bodies;
+    record_changes(changes, &rb, |c| Some(RecordedChange::RigidBody(c)));
+    record_changes(changes, &mprops, |c| {
+        Some(RecordedChange::AdditionalMassProperties(c))
+    });
+    record_changes(changes, &locked, |c| Some(RecordedChange::LockedAxes(c)));
+    record_changes(changes, &force, |c| Some(RecordedChange::ExternalForce(c)));
+    // Applied impulses are reset by `bevy_rapier` right after being applied.
+    record_changes(changes, &impulse, |c| {
+        (c != ExternalImpulse::default()).then_some(RecordedChange::ExternalImpulse(c))
+    });
+    record_changes(changes, &gravity, |c| Some(RecordedChange::GravityScale(c)));
+    record_changes(changes, &ccd, |c| Some(RecordedChange::Ccd(c)));
+    record_changes(changes, &dominance, |c| Some(RecordedChange::Dominance(c)));
+    record_changes(changes, &damping, |c| Some(RecordedChange::Damping(c)));
+    record_changes(changes, &disabled, |_| {
+        Some(RecordedChange::RigidBodyDisabled)
+    });
+
+    // Transforms, velocities and sleeping states are also written back by `bevy_rapier`
+    // after each step, so we only record the ones which differ from the Rapier state.
+    for (entity, handle, link, global_transform, transform, controller) in changed_transforms.iter()
+    {
+        // The movement of character controllers is replayed from their translation.
+        if controller.is_some_and(|controller| controller.translation.is_some()) {
+            continue;
+        }
+        let (Ok(set), Ok(config)) = (rigid_body_sets.get(link.0), config.get(link.0)) else {
+            continue;
+        };
+        if config.force_update_from_transform_changes
+            || set.last_body_transform_set.get(&handle.0) != Some(global_transform)
+        {
+            changes.push((entity, RecordedChange::Transform(*transform)));
+        }
+    }
+
+    let (changed_velocities, changed_sleeping) = changed_states;
+    for (entity, handle, link, velocity) in changed_velocities.iter() {
+        let Some(rb) = rigid_body_sets
+            .get(link.0)
+            .ok()
+            .and_then(|set| set.bodies.get(handle.0))
+        else {
+            continue;
+        };
+        let current = Velocity {
+            linvel: (*rb.linvel()).into(),
+            #[cfg(feature = "dim3")]
+            angvel: (*rb.angvel()).into(),
+            #[cfg(feature = "dim2")]
+            angvel: rb.angvel(),
+        };
+        if current != *velocity {
+            changes.push((entity, RecordedChange::Velocity(*velocity)));
+        }
+    }
+
+    for (entity, handle, link, sleeping) in changed_sleeping.iter() {
+        let Some(rb) = rigid_body_sets
+            .get(link.0)
+            .ok()
+            .and_then(|set| set.bodies.get(handle.0))
+        else {
+            continue;
+        };
+        let activation = rb.activation();
+        if activation.sleeping != sleeping.sleeping
+            || activation.normalized_linear_threshold != sleeping.normalized_linear_threshold
+            || activation.angular_threshold != sleeping.angular_threshold
+        {
+            changes.push((entity, RecordedChange::Sleeping(*sleeping)));
+        }
+    }
+
+    let (shapes, friction, restitution, groups, solver_groups, mprops, events, sensor, disabled) =
+        changed_colliders;
+    record_changes(changes, &shapes, |c| Some(RecordedChange::Collider(c)));
+    record_changes(changes, &friction, |c| Some(RecordedChange::Friction(c)));
+    record_changes(changes, &restitution, |c| {
+        Some(RecordedChange::Restitution(c))
+    });
+    record_changes(changes, &groups, |c| {
+        Some(RecordedChange::CollisionGroups(c))
+    });
+    record_changes(changes, &solver_groups, |c| {
+        Some(RecordedChange::SolverGroups(c))
+    });
+    record_changes(changes, &mprops, |c| {
+        Some(RecordedChange::ColliderMassProperties(c))
+    });
+    record_changes(changes, &events, |c| Some(RecordedChange::ActiveEvents(c)));
+    record_changes(changes, &sensor, |_| Some(RecordedChange::Sensor));
+    record_changes(changes, &disabled, |_| {
+        Some(RecordedChange::ColliderDisabled)
+    });
+
+    let (impulse_joints, multibody_joints) = changed_joints;
+    record_changes(changes, &impulse_joints, |joint| {
+        Some(RecordedChange::ImpulseJoint {
+            parent: joint.parent,
+            data: *joint.data.as_ref(),
+        })
+    });
+    record_changes(changes, &multibody_joints, |joint| {
+        Some(RecordedChange::MultibodyJoint {
+            parent: joint.parent,
+            data: *joint.data.as_ref(),
+        })
+    });
+}
+
+/// System applying the next step of the [`PhysicsReplay`] to the world.
+pub fn replay_recorded_step(world: &mut World) {
+    let timestep_mode = *world.resource::<TimestepMode>();
+    let step = {
+        let mut replay = world.resource_mut::<PhysicsReplay>();
+        let Some(step) = replay.recording.steps.get(replay.next_step).cloned() else {
+            return;
+        };
+        replay.next_step += 1;
+        replay.timestep_mode.get_or_insert(timestep_mode);
+        step
+    };
+
+    // The physics is stepped by the recorded time delta instead of the one of the replaying app,
+    // without touching its `Time`.
+    let delta = step.delta.as_secs_f32();
+    *world.resource_mut::<TimestepMode>() = match step.timestep_mode {
+        TimestepMode::Variable {
+            max_dt,
+            time_scale,
+            substeps,
+        } => TimestepMode::Fixed {
+            dt: (delta * time_scale).min(max_dt),
+            substeps,
+        },
+        TimestepMode::Interpolated { .. } => {
+            let correction = delta - world.resource::<Time>().delta_secs();
+            for mut sim_to_render_time in
+                world.query::<&mut SimulationToRenderTime>().iter_mut(world)
+            {
+                sim_to_render_time.diff += correction;
+            }
+            step.timestep_mode
+        }
+        TimestepMode::Fixed { .. } => step.timestep_mode,
+    };
+
+    for (entity, change) in step.changes {
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            log::warn!("Could not replay a change on {entity}: the entity doesn't exist.");
+            continue;
+        };
+        match change {
+            RecordedChange::RigidBody(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Transform(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Velocity(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::AdditionalMassProperties(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::LockedAxes(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::ExternalForce(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::ExternalImpulse(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::GravityScale(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Ccd(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Dominance(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Sleeping(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Damping(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::RigidBodyDisabled => {
+                entity_mut.insert(RigidBodyDisabled);
+            }
+            RecordedChange::Collider(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Friction(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Restitution(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::CollisionGroups(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::SolverGroups(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::ColliderMassProperties(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::ActiveEvents(c) => {
+                entity_mut.insert(c);
+            }
+            RecordedChange::Sensor => {
+                entity_mut.insert(Sensor);
+            }
+            RecordedChange::ColliderDisabled => {
+                entity_mut.insert(ColliderDisabled);
+            }
+            RecordedChange::ImpulseJoint { parent, data } => {
+                entity_mut.insert(ImpulseJoint::new(parent, TypedJoint::GenericJoint(data)));
+            }
+            RecordedChange::MultibodyJoint { parent, data } => {
+                entity_mut.insert(MultibodyJoint::new(parent, TypedJoint::GenericJoint(data)));
+            }
+            RecordedChange::CharacterTranslation(translation) => {
+                if let Some(mut controller) = entity_mut.get_mut::<KinematicCharacterController>() {
+                    controller.translation = Some(translation);
+                }
+            }
+            RecordedChange::Removed(component) => match component {
+                RecordedComponent::RigidBody => {
+                    entity_mut.remove::<RigidBody>();
+                }
+                RecordedComponent::RigidBodyDisabled => {
+                    entity_mut.remove::<RigidBodyDisabled>();
+                }
+                RecordedComponent::Collider => {
+                    entity_mut.remove::<Collider>();
+                }
+                RecordedComponent::Sensor => {
+                    entity_mut.remove::<Sensor>();
+                }
+                RecordedComponent::ColliderDisabled => {
+                    entity_mut.remove::<ColliderDisabled>();
+                }
+                RecordedComponent::ImpulseJoint => {
+                    entity_mut.remove::<ImpulseJoint>();
+                }
+                RecordedComponent::MultibodyJoint => {
+                    entity_mut.remove::<MultibodyJoint>();
+                }
+            },
+        }
+    }
+}
+
+/// System restoring the [`TimestepMode`] of the app once the [`PhysicsReplay`] is finished.
+pub fn finish_replay(mut replay: ResMut<PhysicsReplay>, mut timestep_mode: ResMut<TimestepMode>) {
+    if replay.is_finished() {
+        if let Some(mode) = replay.timestep_mode.take() {
+            *timestep_mode = mode;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+
+    use super::*;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+
+    fn setup_app(app: &mut App, frame_duration: f32) {
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            frame_duration,
+        )));
+    }
+
+    fn spawn_scene(world: &mut World) -> Entity {
+        #[cfg(feature = "dim3")]
+        let ground = Collider::cuboid(10.0, 0.5, 10.0);
+        #[cfg(feature = "dim2")]
+        let ground = Collider::cuboid(10.0, 0.5);
+        world.spawn((Transform::default(), RigidBody::Fixed, ground));
+        world
+            .spawn((
+                Transform::from_xyz(0.0, 3.0, 0.0),
+                RigidBody::Dynamic,
+                Velocity::default(),
+                Collider::ball(0.5),
+            ))
+            .id()
+    }
+
+    fn play_with_ball(
+        mut commands: Commands,
+        mut frame: Local<u32>,
+        mut balls: Query<(Entity, &mut Transform, &mut Velocity, &mut ExternalImpulse)>,
+    ) {
+        *frame += 1;
+        let Ok((ball, mut transform, mut velocity, mut impulse)) = balls.single_mut() else {
+            return;
+        };
+        match *frame {
+            5 => impulse.impulse = Vect::X * 2.0,
+            20 => velocity.linvel = Vect::Y * 4.0,
+            25 => {
+                commands.entity(ball).insert(RigidBodyDisabled);
+            }
+            30 => {
+                commands.entity(ball).remove::<RigidBodyDisabled>();
+            }
+            40 => transform.translation.x = -1.0,
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn replay_reproduces_recorded_run() {
+        let mut recorded_app = App::new();
+        setup_app(&mut recorded_app, 1.0 / 90.0);
+        recorded_app
+            .add_plugins(RapierRecorderPlugin::default())
+            .add_systems(Update, play_with_ball);
+        recorded_app.finish();
+        let ball = spawn_scene(recorded_app.world_mut());
+        recorded_app
+            .world_mut()
+            .entity_mut(ball)
+            .insert(ExternalImpulse::default());
+        for _ in 0..80 {
+            recorded_app.update();
+        }
+        let recording = recorded_app
+            .world_mut()
+            .resource_mut::<PhysicsRecorder>()
+            .take_recording();
+        assert!(recording.steps.iter().any(|step| step.changes.iter().any(
+            |(_, change)| matches!(
+                change,
+                RecordedChange::Removed(RecordedComponent::RigidBodyDisabled)
+            )
+        )));
+        let expected = *recorded_app.world().get::<Transform>(ball).unwrap();
+
+        // Replay at a different frame rate: the recorded time deltas must be used instead.
+        let mut replay_app = App::new();
+        setup_app(&mut replay_app, 1.0 / 30.0);
+        replay_app.add_plugins(RapierReplayPlugin::new(recording));
+        replay_app.finish();
+        let replayed_ball = spawn_scene(replay_app.world_mut());
+        replay_app
+            .world_mut()
+            .entity_mut(replayed_ball)
+            .insert(ExternalImpulse::default());
+        assert_eq!(ball, replayed_ball);
+        let mut replayed_frames = 0;
+        while !replay_app.world().resource::<PhysicsReplay>().is_finished() {
+            replay_app.update();
+            replayed_frames += 1;
+        }
+        assert_eq!(replayed_frames, 80);
+        assert_eq!(
+            *replay_app.world().resource::<TimestepMode>(),
+            TimestepMode::default(),
+            "The timestep mode should be restored once the replay is finished"
+        );
+        // The time of the replaying app is driven by its own frame rate only.
+        assert_eq!(
+            replay_app.world().resource::<Time>().elapsed(),
+            Duration::from_secs_f32(1.0 / 30.0) * (replayed_frames - 1)
+        );
+
+        assert_eq!(
+            expected,
+            *replay_app.world().get::<Transform>(ball).unwrap(),
+            "The replayed run should end in the exact same state"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::dynamics::ReadMassProperties;
-use crate::geometry::Collider;
+use crate::geometry::{Collider, ColliderShape};
 use crate::plugin::context::systemparams::{RapierEntity, RAPIER_CONTEXT_EXPECT_ERROR};
-use crate::plugin::context::RapierContextEntityLink;
+use crate::plugin::context::{RapierContextEntityLink, RapierContextSimulation};
 use crate::plugin::{
     context::{DefaultRapierContext, RapierContextColliders, RapierRigidBodySet},
     RapierConfiguration,
@@ -9,22 +9,46 @@ use crate::plugin::{
 use crate::prelude::{
     ActiveCollisionTypes, ActiveEvents, ActiveHooks, ColliderDisabled, ColliderMassProperties,
     ColliderScale, CollidingEntities, CollisionEvent, CollisionGroups, ContactForceEventThreshold,
-    ContactSkin, Friction, MassModifiedEvent, MassProperties, RapierColliderHandle,
-    RapierRigidBodyHandle, Restitution, Sensor, SolverGroups,
+    ContactSkin, FilteredTriggerZone, Friction, MassModifiedEvent, MassProperties,
+    RapierColliderHandle, RapierRigidBodyHandle, Restitution, Sensor, SolverGroups, TriggerZone,
+    TriggerZoneEnter, TriggerZoneExit, TriggerZoneExitReason, TriggerZoneFilter,
+    TriggerZoneOccupant, TriggerZoneStay,
 };
 use crate::utils;
+use bevy::ecs::query::QueryFilter;
 use bevy::prelude::*;
+use bevy::reflect::TypePath;
 use rapier::dynamics::RigidBodyHandle;
 use rapier::geometry::ColliderBuilder;
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use {
-    crate::prelude::{AsyncCollider, AsyncSceneCollider},
+    crate::geometry::MeshColliderCache,
+    crate::prelude::{
+        AsyncCollider, AsyncColliderStatus, AsyncColliderTask, AsyncSceneCollider, ColliderFromMesh,
+    },
     bevy::scene::SceneInstance,
 };
 
+#[cfg(all(feature = "dim3", feature = "gltf"))]
+use crate::geometry::{
+    gltf_extras::{read_gltf_extras, GltfExtrasQuery},
+    gltf_physics::GltfPhysicsAssets,
+};
+#[cfg(feature = "collider-asset")]
+use crate::geometry::{AsyncColliderAsset, ColliderAsset};
+#[cfg(any(feature = "async-collider", feature = "collider-asset"))]
+use bevy::platform::collections::HashSet;
+
 #[cfg(feature = "dim2")]
 use bevy::math::Vec3Swizzles;
 
+/// The component referencing the mesh of an entity.
+#[cfg(all(feature = "dim2", feature = "async-collider"))]
+type MeshComponent = Mesh2d;
+/// The component referencing the mesh of an entity.
+#[cfg(all(feature = "dim3", feature = "async-collider"))]
+type MeshComponent = Mesh3d;
+
 /// Components related to colliders.
 pub type ColliderComponents<'a> = (
     (Entity, Option<&'a RapierContextEntityLink>),
@@ -504,57 +528,286 @@ pub fn init_colliders(
     }
 }
 
-/// System responsible for creating `Collider` components from `AsyncCollider` components if the
-/// corresponding mesh has become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+/// System responsible for rebuilding `Collider` components from `ColliderShape` components
+/// that were added or modified, keeping the scale applied to the previous collider.
+pub fn init_collider_shapes(
+    mut commands: Commands,
+    config: Query<&RapierConfiguration>,
+    mut shapes: Query<
+        (
+            Entity,
+            &ColliderShape,
+            Option<&mut Collider>,
+            Option<&RapierContextEntityLink>,
+        ),
+        Changed<ColliderShape>,
+    >,
+) {
+    for (entity, shape, collider, link) in shapes.iter_mut() {
+        let Some(mut new_collider) = shape.to_collider() else {
+            log::error!("Unable to build a collider for {entity} from {shape:?}");
+            continue;
+        };
+
+        match collider {
+            Some(mut collider) => {
+                // Colliders which aren't attached to a context yet get their scale in `apply_scale`.
+                if let Some(config) = link.and_then(|link| config.get(link.0).ok()) {
+                    new_collider.set_scale(collider.scale(), config.scaled_shape_subdivision);
+                }
+                *collider = new_collider;
+            }
+            None => {
+                commands.entity(entity).insert(new_collider);
+            }
+        }
+    }
+}
+
+/// System responsible for evicting the colliders computed from modified or removed meshes from
+/// the `MeshColliderCache`.
+#[cfg(feature = "async-collider")]
+pub fn invalidate_mesh_collider_cache(
+    mut cache: ResMut<MeshColliderCache>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+) {
+    for event in mesh_events.read() {
+        match event {
+            AssetEvent::Modified { id }
+            | AssetEvent::Removed { id }
+            | AssetEvent::Unused { id } => {
+                cache.invalidate(*id);
+            }
+            AssetEvent::Added { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+        }
+    }
+}
+
+/// System responsible for requesting the computation of `Collider` components from
+/// `AsyncCollider` components if the corresponding mesh has become available.
+#[cfg(feature = "async-collider")]
 pub fn init_async_colliders(
     mut commands: Commands,
     meshes: Res<Assets<Mesh>>,
-    async_colliders: Query<(Entity, &Mesh3d, &AsyncCollider)>,
+    mut cache: ResMut<MeshColliderCache>,
+    async_colliders: Query<(
+        Entity,
+        &MeshComponent,
+        Ref<AsyncCollider>,
+        Option<&AsyncColliderStatus>,
+    )>,
 ) {
-    for (entity, mesh_handle, async_collider) in async_colliders.iter() {
+    for (entity, mesh_handle, async_collider, status) in async_colliders.iter() {
+        if status.is_some() && !async_collider.is_changed() {
+            continue;
+        }
         if let Some(mesh) = meshes.get(mesh_handle) {
-            match Collider::from_bevy_mesh(mesh, &async_collider.0) {
-                Some(collider) => {
+            cache.request(mesh_handle.id(), mesh, &async_collider.0);
+            commands.entity(entity).insert((
+                AsyncColliderTask {
+                    mesh: mesh_handle.id(),
+                    shape: async_collider.0.clone(),
+                },
+                AsyncColliderStatus::Pending,
+            ));
+        }
+    }
+}
+
+/// System responsible for requesting the computation of the `Collider` of the entities with a
+/// `ColliderFromMesh` component whenever it, their mesh handle, or their mesh asset changes.
+#[cfg(feature = "async-collider")]
+pub fn update_colliders_from_meshes(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    mut cache: ResMut<MeshColliderCache>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    colliders_from_meshes: Query<(Entity, Ref<MeshComponent>, Ref<ColliderFromMesh>)>,
+) {
+    let mut updated = HashSet::<AssetId<Mesh>>::default();
+    for event in mesh_events.read() {
+        match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => {
+                updated.insert(*id);
+            }
+            AssetEvent::Removed { .. } | AssetEvent::Unused { .. } => {}
+        }
+    }
+
+    for (entity, mesh_handle, collider_from_mesh) in colliders_from_meshes.iter() {
+        if !collider_from_mesh.is_changed()
+            && !mesh_handle.is_changed()
+            && !updated.contains(&mesh_handle.id())
+        {
+            continue;
+        }
+        if let Some(mesh) = meshes.get(&*mesh_handle) {
+            cache.request(mesh_handle.id(), mesh, &collider_from_mesh.0);
+            commands.entity(entity).insert((
+                AsyncColliderTask {
+                    mesh: mesh_handle.id(),
+                    shape: collider_from_mesh.0.clone(),
+                },
+                AsyncColliderStatus::Pending,
+            ));
+        }
+    }
+}
+
+/// System responsible for inserting the `Collider` components requested by `init_async_colliders`,
+/// `update_colliders_from_meshes` and `init_async_scene_colliders`, once they are computed in the
+/// `MeshColliderCache`.
+#[cfg(feature = "async-collider")]
+pub fn poll_async_collider_tasks(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    mut cache: ResMut<MeshColliderCache>,
+    tasks: Query<(Entity, &AsyncColliderTask, Option<&Name>)>,
+) {
+    cache.poll_tasks();
+
+    for (entity, task, name) in tasks.iter() {
+        let collider = match cache.get(task.mesh, &task.shape) {
+            Some(collider) => collider,
+            None => {
+                // The collider may have been evicted from the cache since it was requested.
+                if let Some(mesh) = meshes.get(task.mesh) {
+                    cache.request(task.mesh, mesh, &task.shape);
+                } else {
+                    log::error!("Unable to generate collider for {entity}: its mesh was removed");
                     commands
                         .entity(entity)
-                        .insert(collider)
-                        .remove::<AsyncCollider>();
+                        .remove::<AsyncColliderTask>()
+                        .insert(AsyncColliderStatus::Failed);
                 }
-                None => log::error!("Unable to generate collider from mesh {mesh:?}"),
+                continue;
+            }
+        };
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<AsyncColliderTask>();
+        match collider {
+            Some(collider) => {
+                entity_commands
+                    .insert(collider.clone())
+                    .remove::<(AsyncCollider, AsyncColliderStatus)>();
+            }
+            None => {
+                match name {
+                    Some(name) => log::error!(
+                        "Unable to generate collider from the mesh of {entity} with name {name}"
+                    ),
+                    None => log::error!("Unable to generate collider from the mesh of {entity}"),
+                }
+                entity_commands.insert(AsyncColliderStatus::Failed);
             }
         }
     }
 }
 
-/// System responsible for creating `Collider` components from `AsyncSceneCollider` components if the
-/// corresponding scene has become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+/// System responsible for inserting the `Collider` and properties of the `ColliderAsset`
+/// referenced by `AsyncColliderAsset` components, whenever the asset is loaded or modified.
+#[cfg(feature = "collider-asset")]
+pub fn init_async_collider_assets(
+    mut commands: Commands,
+    assets: Res<Assets<ColliderAsset>>,
+    mut asset_events: EventReader<AssetEvent<ColliderAsset>>,
+    async_colliders: Query<(Entity, Ref<AsyncColliderAsset>)>,
+) {
+    let mut updated = HashSet::<AssetId<ColliderAsset>>::default();
+    for event in asset_events.read() {
+        match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => {
+                updated.insert(*id);
+            }
+            AssetEvent::Removed { .. } | AssetEvent::Unused { .. } => {}
+        }
+    }
+
+    for (entity, async_collider) in async_colliders.iter() {
+        if !async_collider.is_changed() && !updated.contains(&async_collider.0.id()) {
+            continue;
+        }
+        if let Some(asset) = assets.get(&async_collider.0) {
+            asset.insert_into(&mut commands.entity(entity));
+        }
+    }
+}
+
+/// System responsible for requesting the computation of `Collider` components from
+/// `AsyncSceneCollider` components if the corresponding scene has become available.
+#[cfg(feature = "async-collider")]
 pub fn init_async_scene_colliders(
     mut commands: Commands,
-    meshes: Res<Assets<Mesh>>,
+    // The glTF physics may add meshes merged from the primitives of glTF meshes.
+    #[cfg_attr(not(all(feature = "dim3", feature = "gltf")), allow(unused_mut))] mut meshes: ResMut<
+        Assets<Mesh>,
+    >,
+    mut cache: ResMut<MeshColliderCache>,
     scene_spawner: Res<SceneSpawner>,
     async_colliders: Query<(Entity, &SceneInstance, &AsyncSceneCollider)>,
     children: Query<&Children>,
-    mesh_handles: Query<(&Name, &Mesh3d)>,
+    mesh_handles: Query<(&Name, &MeshComponent)>,
+    #[cfg(all(feature = "dim3", feature = "gltf"))] gltf_extras: GltfExtrasQuery,
+    #[cfg(all(feature = "dim3", feature = "gltf"))] gltf_physics: GltfPhysicsAssets,
 ) {
     for (scene_entity, scene_instance, async_collider) in async_colliders.iter() {
         if scene_spawner.instance_is_ready(**scene_instance) {
+            #[cfg(all(feature = "dim3", feature = "gltf"))]
+            if let Some(gltf) = &async_collider.gltf_physics {
+                if let Some(error) = gltf_physics.import_error(gltf) {
+                    log::warn!(
+                        "Ignoring the glTF physics of the scene of {scene_entity}: {error}."
+                    );
+                } else if gltf_physics.is_loaded(gltf) {
+                    gltf_physics.import(
+                        &mut commands,
+                        gltf,
+                        scene_entity,
+                        &children,
+                        &mut meshes,
+                        &mut cache,
+                    );
+                } else {
+                    continue;
+                }
+            }
+
             for child_entity in children.iter_descendants(scene_entity) {
                 if let Ok((name, handle)) = mesh_handles.get(child_entity) {
                     let shape = async_collider
                         .named_shapes
                         .get(name.as_str())
                         .unwrap_or(&async_collider.shape);
+                    #[cfg(all(feature = "dim3", feature = "gltf"))]
+                    let extras = async_collider
+                        .use_gltf_extras
+                        .then(|| read_gltf_extras(&mut commands, child_entity, &gltf_extras));
+                    #[cfg(all(feature = "dim3", feature = "gltf"))]
+                    let extras_shape = extras
+                        .as_ref()
+                        .and_then(|extras| extras.shape)
+                        .map(|shape| shape.computed_shape());
+                    #[cfg(all(feature = "dim3", feature = "gltf"))]
+                    let shape = extras_shape.as_ref().unwrap_or(shape);
                     if let Some(shape) = shape {
                         let mesh = meshes.get(handle).unwrap(); // NOTE: Mesh is already loaded
-                        match Collider::from_bevy_mesh(mesh, shape) {
-                            Some(collider) => {
-                                commands.entity(child_entity).insert(collider);
-                            }
-                            None => log::error!(
-                                "Unable to generate collider from mesh {mesh:?} with name {name}"
-                            ),
+                        cache.request(handle.id(), mesh, shape);
+                        let mut entity = commands.entity(child_entity);
+                        entity.insert((
+                            AsyncColliderTask {
+                                mesh: handle.id(),
+                                shape: shape.clone(),
+                            },
+                            AsyncColliderStatus::Pending,
+                        ));
+                        #[cfg(all(feature = "dim3", feature = "gltf"))]
+                        if let Some(extras) = &extras {
+                            extras.insert_collider_properties(&mut entity);
                         }
                     }
                 }
@@ -593,30 +846,249 @@ pub fn update_colliding_entities(
     }
 }
 
+/// The [`TriggerZone`]s updated by a system, with the rapier context of their collider.
+type TriggerZones<'w, 's, F> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut TriggerZone,
+        Option<&'static RapierContextEntityLink>,
+    ),
+    F,
+>;
+
+/// The rapier contexts read to update the [`TriggerZone`]s.
+type TriggerZoneContexts<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static RapierContextSimulation,
+        &'static RapierContextColliders,
+        &'static RapierRigidBodySet,
+    ),
+>;
+
+/// Updates the occupants of the [`TriggerZone`]s without [`TriggerZoneFilter`] from the collision
+/// events of their colliders and the state of their occupants, and triggers the
+/// [`TriggerZoneEnter`], [`TriggerZoneStay`] and [`TriggerZoneExit`] events on the zones.
+pub fn update_trigger_zones(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut zones: TriggerZones<Without<FilteredTriggerZone>>,
+    colliders: Query<(&RapierColliderHandle, &RapierContextEntityLink)>,
+    contexts: TriggerZoneContexts,
+) {
+    update_zones(
+        &mut commands,
+        &mut collision_events,
+        &mut zones,
+        &colliders,
+        &contexts,
+        |_| true,
+    );
+}
+
+/// Updates the occupants of the [`TriggerZone`]s with a [`TriggerZoneFilter`] of `T`, like
+/// [`update_trigger_zones`].
+pub fn update_filtered_trigger_zones<T: Component + TypePath>(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    mut zones: TriggerZones<With<TriggerZoneFilter<T>>>,
+    colliders: Query<(&RapierColliderHandle, &RapierContextEntityLink)>,
+    contexts: TriggerZoneContexts,
+    filter: Query<(), With<T>>,
+) {
+    update_zones(
+        &mut commands,
+        &mut collision_events,
+        &mut zones,
+        &colliders,
+        &contexts,
+        |entity| filter.contains(entity),
+    );
+}
+
+fn update_zones<F: QueryFilter>(
+    commands: &mut Commands,
+    collision_events: &mut EventReader<CollisionEvent>,
+    zones: &mut TriggerZones<F>,
+    colliders: &Query<(&RapierColliderHandle, &RapierContextEntityLink)>,
+    contexts: &TriggerZoneContexts,
+    has_component: impl Fn(Entity) -> bool,
+) {
+    // The rapier collider of an entity, and the rapier rigid-body it is attached to.
+    let rapier_collider = |entity: Entity| {
+        let (handle, link) = colliders.get(entity).ok()?;
+        let (_, context_colliders, rigidbody_set) = contexts.get(link.0).ok()?;
+        let collider = context_colliders.colliders.get(handle.0)?;
+        let body = collider
+            .parent()
+            .and_then(|parent| rigidbody_set.bodies.get(parent));
+        Some((collider, body))
+    };
+    let passes_filter = |entity: Entity| {
+        let body_entity = rapier_collider(entity)
+            .and_then(|(_, body)| body)
+            .map(|body| Entity::from_bits(body.user_data as u64));
+        has_component(entity) || body_entity.is_some_and(&has_component)
+    };
+    // Why an occupant must exit its zone, even without collision event.
+    let exit_reason = |entity: Entity| match rapier_collider(entity) {
+        None => Some(TriggerZoneExitReason::Despawned),
+        Some((collider, body))
+            if !collider.is_enabled() || body.is_some_and(|body| !body.is_enabled()) =>
+        {
+            Some(TriggerZoneExitReason::Disabled)
+        }
+        Some(_) => (!passes_filter(entity)).then_some(TriggerZoneExitReason::Filtered),
+    };
+
+    let mut entered = Vec::new();
+    for event in collision_events.read() {
+        let (entity1, entity2, started) = match *event {
+            CollisionEvent::Started(entity1, entity2, _) => (entity1, entity2, true),
+            CollisionEvent::Stopped(entity1, entity2, _) => (entity1, entity2, false),
+        };
+        for (zone_entity, occupant) in [(entity1, entity2), (entity2, entity1)] {
+            let Ok((_, mut zone, _)) = zones.get_mut(zone_entity) else {
+                continue;
+            };
+            let index = zone
+                .occupants
+                .iter()
+                .position(|other| other.entity == occupant);
+            match index {
+                None if started && passes_filter(occupant) => {
+                    zone.occupants.push(TriggerZoneOccupant {
+                        entity: occupant,
+                        time: 0.0,
+                    });
+                    entered.push((zone_entity, occupant));
+                    commands.trigger_targets(TriggerZoneEnter { occupant }, zone_entity);
+                }
+                Some(index) if !started => {
+                    let removed = zone.occupants.remove(index);
+                    commands.trigger_targets(
+                        TriggerZoneExit {
+                            occupant,
+                            time: removed.time,
+                            reason: exit_reason(occupant).unwrap_or(TriggerZoneExitReason::Left),
+                        },
+                        zone_entity,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    for (zone_entity, mut zone, link) in zones.iter_mut() {
+        if zone.occupants.is_empty() {
+            continue;
+        }
+        // The occupants stay in the zone for the time simulated by the rapier context of the zone.
+        let delta = link
+            .and_then(|link| contexts.get(link.0).ok())
+            .map_or(0.0, |(simulation, _, _)| simulation.simulated_time);
+        zone.occupants.retain_mut(|occupant| {
+            if let Some(reason) = exit_reason(occupant.entity) {
+                commands.trigger_targets(
+                    TriggerZoneExit {
+                        occupant: occupant.entity,
+                        time: occupant.time,
+                        reason,
+                    },
+                    zone_entity,
+                );
+                return false;
+            }
+            if !entered.contains(&(zone_entity, occupant.entity)) {
+                occupant.time += delta;
+                commands.trigger_targets(
+                    TriggerZoneStay {
+                        occupant: occupant.entity,
+                        time: occupant.time,
+                    },
+                    zone_entity,
+                );
+            }
+            true
+        });
+    }
+}
+
 #[cfg(test)]
 #[allow(missing_docs)]
 pub mod test {
+    /// Updates the app until the tasks computing the async colliders are finished.
+    #[cfg(feature = "async-collider")]
+    fn update_until_colliders_computed(app: &mut bevy::app::App) {
+        use crate::geometry::AsyncColliderTask;
+        use std::time::{Duration, Instant};
+
+        let start = Instant::now();
+        loop {
+            app.update();
+            let world = app.world_mut();
+            if world
+                .query::<&AsyncColliderTask>()
+                .iter(world)
+                .next()
+                .is_none()
+            {
+                return;
+            }
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "Colliders should be computed in time"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
     #[test]
     #[cfg(all(feature = "dim3", feature = "async-collider"))]
     fn async_collider_initializes() {
         use super::*;
-        use bevy::{render::mesh::MeshPlugin, scene::ScenePlugin};
+        use bevy::{
+            app::TaskPoolPlugin,
+            asset::RenderAssetUsages,
+            render::mesh::{MeshPlugin, PrimitiveTopology},
+            scene::ScenePlugin,
+        };
 
         let mut app = App::new();
-        app.add_plugins((AssetPlugin::default(), MeshPlugin, ScenePlugin));
-        app.add_systems(Update, init_async_colliders);
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            MeshPlugin,
+            ScenePlugin,
+        ));
+        app.init_resource::<MeshColliderCache>().add_systems(
+            Update,
+            (init_async_colliders, poll_async_collider_tasks).chain(),
+        );
 
         app.finish();
 
         let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
         let cube = meshes.add(Cuboid::default());
+        let empty = meshes.add(Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        ));
 
         let entity = app
             .world_mut()
             .spawn((Mesh3d(cube), AsyncCollider::default()))
             .id();
+        let invalid = app
+            .world_mut()
+            .spawn((Mesh3d(empty), AsyncCollider::default()))
+            .id();
 
-        app.update();
+        update_until_colliders_computed(&mut app);
 
         let entity = app.world().entity(entity);
         assert!(
@@ -627,17 +1099,263 @@ pub mod test {
             entity.get::<AsyncCollider>().is_none(),
             "AsyncCollider component should be removed after Collider component creation"
         );
+        assert!(entity.get::<AsyncColliderStatus>().is_none());
+
+        let invalid = app.world().entity(invalid);
+        assert!(invalid.get::<Collider>().is_none());
+        assert!(
+            invalid.get::<AsyncCollider>().is_some(),
+            "AsyncCollider component should be kept to retry the computation"
+        );
+        assert_eq!(
+            invalid.get::<AsyncColliderStatus>(),
+            Some(&AsyncColliderStatus::Failed)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn async_collider_fails_when_mesh_is_removed() {
+        use super::*;
+        use crate::geometry::AsyncColliderTask;
+        use bevy::{app::TaskPoolPlugin, ecs::system::RunSystemOnce, render::mesh::MeshPlugin};
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            MeshPlugin,
+        ));
+        app.init_resource::<MeshColliderCache>()
+            .add_systems(Update, poll_async_collider_tasks);
+        app.finish();
+
+        let cube = app
+            .world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default());
+        let entity = app
+            .world_mut()
+            .spawn((Mesh3d(cube.clone()), AsyncCollider::default()))
+            .id();
+        app.world_mut()
+            .run_system_once(init_async_colliders)
+            .unwrap();
+        assert!(app.world().get::<AsyncColliderTask>(entity).is_some());
+
+        // Drop the mesh, and evict its pending collider like its removal event would.
+        app.world_mut().resource_mut::<Assets<Mesh>>().remove(&cube);
+        app.world_mut()
+            .resource_mut::<MeshColliderCache>()
+            .invalidate(cube.id());
+        app.update();
+
+        let entity = app.world().entity(entity);
+        assert!(entity.get::<Collider>().is_none());
+        assert!(
+            entity.get::<AsyncColliderTask>().is_none(),
+            "The collider shouldn't stay pending forever"
+        );
+        assert_eq!(
+            entity.get::<AsyncColliderStatus>(),
+            Some(&AsyncColliderStatus::Failed)
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn async_colliders_share_cached_shapes() {
+        use super::*;
+        use bevy::{app::TaskPoolPlugin, render::mesh::MeshPlugin, scene::ScenePlugin};
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            MeshPlugin,
+            ScenePlugin,
+        ));
+        app.init_resource::<MeshColliderCache>().add_systems(
+            Update,
+            (
+                invalidate_mesh_collider_cache,
+                init_async_colliders,
+                poll_async_collider_tasks,
+            )
+                .chain(),
+        );
+
+        app.finish();
+
+        let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+        let cube = meshes.add(Cuboid::default());
+
+        let spawn_cubes = |app: &mut App| -> Vec<Entity> {
+            (0..3)
+                .map(|_| {
+                    app.world_mut()
+                        .spawn((Mesh3d(cube.clone()), AsyncCollider::default()))
+                        .id()
+                })
+                .collect()
+        };
+        let entities = spawn_cubes(&mut app);
+        update_until_colliders_computed(&mut app);
+
+        let shape = |app: &App, entity: Entity| {
+            std::sync::Arc::as_ptr(&app.world().get::<Collider>(entity).unwrap().raw.0)
+        };
+        assert_eq!(app.world().resource::<MeshColliderCache>().len(), 1);
+        assert!(
+            entities
+                .iter()
+                .all(|entity| std::ptr::addr_eq(shape(&app, *entity), shape(&app, entities[0]))),
+            "Colliders computed from the same mesh should share their shape"
+        );
+
+        app.world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .insert(&cube, Cuboid::new(2.0, 2.0, 2.0).into());
+        // Asset events are sent at the end of the frame.
+        app.update();
+        app.update();
+        assert!(
+            app.world().resource::<MeshColliderCache>().is_empty(),
+            "Modifying the mesh should evict its colliders"
+        );
+
+        let new_entities = spawn_cubes(&mut app);
+        update_until_colliders_computed(&mut app);
+        assert!(!std::ptr::addr_eq(
+            shape(&app, new_entities[0]),
+            shape(&app, entities[0])
+        ));
+        assert!(std::ptr::addr_eq(
+            shape(&app, new_entities[0]),
+            shape(&app, new_entities[2])
+        ));
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn collider_from_mesh_follows_mesh_changes() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+        use crate::prelude::ComputedColliderShape;
+        use bevy::time::TimePlugin;
+
+        let mut app = App::new();
+        app.add_plugins((
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.finish();
+
+        let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+        let cube = meshes.add(Cuboid::default());
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                ReadMassProperties::default(),
+                Mesh3d(cube.clone()),
+                ColliderFromMesh(ComputedColliderShape::ConvexHull),
+            ))
+            .id();
+
+        update_until_colliders_computed(&mut app);
+        app.update();
+        let mass = |app: &App| app.world().get::<ReadMassProperties>(entity).unwrap().mass;
+        assert!((mass(&app) - 1.0).abs() < 1.0e-5);
+
+        app.world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .insert(&cube, Cuboid::new(2.0, 2.0, 2.0).into());
+        // Asset events are sent at the end of the frame.
+        app.update();
+        update_until_colliders_computed(&mut app);
+        app.update();
+        assert!(
+            (mass(&app) - 8.0).abs() < 1.0e-5,
+            "The collider and mass properties should be updated with the mesh"
+        );
+        assert!(
+            app.world().get::<ColliderFromMesh>(entity).is_some(),
+            "ColliderFromMesh component should be kept"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim2", feature = "async-collider"))]
+    fn async_collider_initializes_from_2d_mesh() {
+        use super::*;
+        use crate::geometry::ComputedColliderShape;
+        use bevy::{app::TaskPoolPlugin, render::mesh::MeshPlugin, scene::ScenePlugin};
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            MeshPlugin,
+            ScenePlugin,
+        ));
+        app.init_resource::<MeshColliderCache>().add_systems(
+            Update,
+            (init_async_colliders, poll_async_collider_tasks).chain(),
+        );
+
+        app.finish();
+
+        let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+        let rectangle = meshes.add(Rectangle::new(2.0, 1.0));
+
+        let polyline = app
+            .world_mut()
+            .spawn((
+                Mesh2d(rectangle.clone()),
+                AsyncCollider(ComputedColliderShape::Polyline),
+            ))
+            .id();
+        let convex_hull = app
+            .world_mut()
+            .spawn((
+                Mesh2d(rectangle),
+                AsyncCollider(ComputedColliderShape::ConvexHull),
+            ))
+            .id();
+
+        update_until_colliders_computed(&mut app);
+
+        let polyline = app.world().get::<Collider>(polyline).unwrap();
+        assert_eq!(
+            polyline.as_polyline().unwrap().num_segments(),
+            4,
+            "The shared diagonal of the rectangle shouldn't be part of its outline"
+        );
+        let convex_hull = app.world().get::<Collider>(convex_hull).unwrap();
+        assert_eq!(convex_hull.as_convex_polygon().unwrap().points().count(), 4);
     }
 
     #[test]
     #[cfg(all(feature = "dim3", feature = "async-collider"))]
     fn async_scene_collider_initializes() {
         use super::*;
-        use bevy::{render::mesh::MeshPlugin, scene::ScenePlugin};
+        use bevy::{app::TaskPoolPlugin, render::mesh::MeshPlugin, scene::ScenePlugin};
 
         let mut app = App::new();
-        app.add_plugins((AssetPlugin::default(), MeshPlugin, ScenePlugin));
-        app.add_systems(PostUpdate, init_async_scene_colliders);
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            MeshPlugin,
+            ScenePlugin,
+        ));
+        app.init_resource::<MeshColliderCache>().add_systems(
+            PostUpdate,
+            (init_async_scene_colliders, poll_async_collider_tasks).chain(),
+        );
 
         let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
         let cube_handle = meshes.add(Cuboid::default());
@@ -668,7 +1386,7 @@ pub mod test {
             .add_children(&[cube, capsule])
             .id();
 
-        app.update();
+        update_until_colliders_computed(&mut app);
 
         assert!(
             app.world().entity(cube).get::<Collider>().is_some(),
@@ -683,4 +1401,74 @@ pub mod test {
             "AsyncSceneCollider component should be removed after Collider components creation"
         );
     }
+
+    #[test]
+    #[cfg(feature = "collider-asset")]
+    fn async_collider_asset_initializes_and_updates() {
+        use super::*;
+
+        let mut app = App::new();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<ColliderAsset>()
+            .add_systems(Update, init_async_collider_assets);
+
+        let mut assets = app.world_mut().resource_mut::<Assets<ColliderAsset>>();
+        let handle = assets.add(ColliderAsset {
+            friction: Some(Friction::new(0.3)),
+            ..ColliderAsset::from(Collider::ball(0.5))
+        });
+        let entity = app
+            .world_mut()
+            .spawn(AsyncColliderAsset(handle.clone()))
+            .id();
+
+        app.update();
+
+        let entity_ref = app.world().entity(entity);
+        assert_eq!(
+            entity_ref
+                .get::<Collider>()
+                .unwrap()
+                .as_ball()
+                .unwrap()
+                .radius(),
+            0.5
+        );
+        assert_eq!(entity_ref.get::<Friction>(), Some(&Friction::new(0.3)));
+        assert!(
+            entity_ref.get::<AsyncColliderAsset>().is_some(),
+            "AsyncColliderAsset component should be kept to follow asset changes"
+        );
+
+        let mut assets = app.world_mut().resource_mut::<Assets<ColliderAsset>>();
+        assets.get_mut(&handle).unwrap().collider = Collider::ball(2.0);
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .get::<Collider>(entity)
+                .unwrap()
+                .as_ball()
+                .unwrap()
+                .radius(),
+            2.0,
+            "Collider should be updated when the asset is modified"
+        );
+
+        let mut assets = app.world_mut().resource_mut::<Assets<ColliderAsset>>();
+        let asset = assets.get_mut(&handle).unwrap();
+        asset.friction = None;
+        asset.restitution = Some(Restitution::new(0.8));
+        app.update();
+
+        let entity_ref = app.world().entity(entity);
+        assert!(
+            entity_ref.get::<Friction>().is_none(),
+            "Friction should be removed when it is removed from the asset"
+        );
+        assert_eq!(
+            entity_ref.get::<Restitution>(),
+            Some(&Restitution::new(0.8))
+        );
+    }
 }
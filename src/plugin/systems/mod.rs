@@ -17,8 +17,12 @@ pub use rigid_body::*;
 pub use writeback::*;
 
 use crate::dynamics::{RapierRigidBodyHandle, TransformInterpolation};
-use crate::pipeline::{CollisionEvent, ContactForceEvent};
-use crate::plugin::context::SimulationToRenderTime;
+use crate::geometry::{ImpactSpeedThreshold, RapierColliderHandle};
+use crate::pipeline::{
+    trigger_collision_observers, CollisionEvent, CollisionStartDetails, ContactForceEvent,
+    ImpactEvent, PropagateCollisionEvents, RapierContextEvent,
+};
+use crate::plugin::context::{RapierContextEntityLink, SimulationToRenderTime};
 use crate::plugin::{RapierConfiguration, TimestepMode};
 use crate::prelude::{BevyPhysicsHooks, BevyPhysicsHooksAdapter};
 use bevy::ecs::system::{StaticSystemParam, SystemParamItem};
@@ -31,8 +35,15 @@ use super::context::{
 
 /// System responsible for advancing the physics simulation, and updating the internal state
 /// for scene queries.
+///
+/// The collision and contact force events are sent as buffered events, along with a
+/// [`RapierContextEvent`] holding the entity of their rapier context, triggered on the entity of
+/// their rapier context, and triggered as observer events on the colliders involved.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn step_simulation<Hooks>(
+    mut commands: Commands,
     mut context: Query<(
+        Entity,
         &mut RapierContextSimulation,
         &mut RapierContextColliders,
         &mut RapierQueryPipeline,
@@ -46,7 +57,19 @@ pub fn step_simulation<Hooks>(
     time: Res<Time>,
     mut collision_events: EventWriter<CollisionEvent>,
     mut contact_force_events: EventWriter<ContactForceEvent>,
+    (mut context_collision_events, mut context_contact_force_events): (
+        EventWriter<RapierContextEvent<CollisionEvent>>,
+        EventWriter<RapierContextEvent<ContactForceEvent>>,
+    ),
+    mut collision_details: EventWriter<CollisionStartDetails>,
+    mut impact_events: EventWriter<ImpactEvent>,
     mut interpolation_query: Query<(&RapierRigidBodyHandle, &mut TransformInterpolation)>,
+    propagate_collision_events: Query<(), With<PropagateCollisionEvents>>,
+    impact_thresholds: Query<(
+        &RapierColliderHandle,
+        &ImpactSpeedThreshold,
+        &RapierContextEntityLink,
+    )>,
 ) where
     Hooks: 'static + BevyPhysicsHooks,
     for<'w, 's> SystemParamItem<'w, 's, Hooks>: BevyPhysicsHooks,
@@ -54,6 +77,7 @@ pub fn step_simulation<Hooks>(
     let hooks_adapter = BevyPhysicsHooksAdapter::new(hooks.into_inner());
 
     for (
+        context_entity,
         mut context,
         mut context_colliders,
         mut query_pipeline,
@@ -66,6 +90,13 @@ pub fn step_simulation<Hooks>(
         let context = &mut *context;
         let context_colliders = &mut *context_colliders;
 
+        context.impact_thresholds = impact_thresholds
+            .iter()
+            .filter(|(_, _, link)| link.0 == context_entity)
+            .map(|(handle, threshold, _)| (handle.0, threshold.0))
+            .collect();
+        context.collect_collision_details = config.collision_details;
+
         if config.physics_pipeline_active {
             context.step_simulation(
                 context_colliders,
@@ -80,13 +111,51 @@ pub fn step_simulation<Hooks>(
                 Some(&mut interpolation_query),
             );
         } else {
+            context.simulated_time = 0.0;
             rigidbody_set.propagate_modified_body_positions_to_colliders(context_colliders);
         }
 
         if config.query_pipeline_active {
             query_pipeline.update_query_pipeline(context_colliders);
         }
+        trigger_collision_observers(
+            &mut commands,
+            context_entity,
+            &context.collision_events_to_send,
+            &context.contact_force_events_to_send,
+            |collider| {
+                context_colliders
+                    .collider_parent(&rigidbody_set, collider)
+                    .filter(|body| propagate_collision_events.contains(*body))
+            },
+        );
+        context_collision_events.write_batch(context.collision_events_to_send.iter().map(
+            |event| RapierContextEvent {
+                context: context_entity,
+                event: *event,
+            },
+        ));
+        context_contact_force_events.write_batch(context.contact_force_events_to_send.iter().map(
+            |event| RapierContextEvent {
+                context: context_entity,
+                event: *event,
+            },
+        ));
         context.send_bevy_events(&mut collision_events, &mut contact_force_events);
+
+        let impacts = std::mem::take(&mut context.impact_events_to_send);
+        for impact in &impacts {
+            commands.trigger_targets(*impact, context_entity);
+        }
+        impact_events.write_batch(impacts);
+        if config.collision_details {
+            for details in &context.collision_details_to_send {
+                commands.trigger_targets(*details, context_entity);
+            }
+            context.send_collision_details(&mut collision_details);
+        } else {
+            context.collision_details_to_send.clear();
+        }
     }
 }
 
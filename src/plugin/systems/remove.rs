@@ -6,7 +6,11 @@ use crate::dynamics::RapierRigidBodyHandle;
 use crate::dynamics::RigidBody;
 use crate::geometry::Collider;
 use crate::geometry::ColliderDisabled;
+use crate::geometry::ColliderMassProperties;
+use crate::geometry::CollisionGroups;
+use crate::geometry::Friction;
 use crate::geometry::RapierColliderHandle;
+use crate::geometry::Restitution;
 use crate::plugin::context::{
     RapierContextColliders, RapierContextJoints, RapierContextSimulation, RapierRigidBodySet,
 };
@@ -15,6 +19,7 @@ use crate::prelude::RigidBodyDisabled;
 use crate::prelude::Sensor;
 use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
+use rapier::geometry::InteractionGroups;
 
 /// System responsible for removing from Rapier the rigid-bodies/colliders/joints which had
 /// their related `bevy_rapier` components removed by the user (through component removal or
@@ -42,6 +47,17 @@ pub fn sync_removals(
     mut removed_sensors: RemovedComponents<Sensor>,
     mut removed_rigid_body_disabled: RemovedComponents<RigidBodyDisabled>,
     mut removed_colliders_disabled: RemovedComponents<ColliderDisabled>,
+    (
+        mut removed_friction,
+        mut removed_restitution,
+        mut removed_collision_groups,
+        mut removed_collider_mass_props,
+    ): (
+        RemovedComponents<Friction>,
+        RemovedComponents<Restitution>,
+        RemovedComponents<CollisionGroups>,
+        RemovedComponents<ColliderMassProperties>,
+    ),
 
     mut mass_modified: EventWriter<MassModifiedEvent>,
 ) {
@@ -214,6 +230,59 @@ pub fn sync_removals(
         }
     }
 
+    /*
+     * Collider properties removal detection: reset them to the values of a collider without them.
+     */
+    for entity in removed_friction.read() {
+        if let Some((mut context, handle)) = find_context(&mut context_writer, |context| {
+            context.1.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = context.1.colliders.get_mut(handle) {
+                let friction = Friction::default();
+                co.set_friction(friction.coefficient);
+                co.set_friction_combine_rule(friction.combine_rule.into());
+            }
+        }
+    }
+
+    for entity in removed_restitution.read() {
+        if let Some((mut context, handle)) = find_context(&mut context_writer, |context| {
+            context.1.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = context.1.colliders.get_mut(handle) {
+                let restitution = Restitution::default();
+                co.set_restitution(restitution.coefficient);
+                co.set_restitution_combine_rule(restitution.combine_rule.into());
+            }
+        }
+    }
+
+    for entity in removed_collision_groups.read() {
+        if let Some((mut context, handle)) = find_context(&mut context_writer, |context| {
+            context.1.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = context.1.colliders.get_mut(handle) {
+                co.set_collision_groups(InteractionGroups::all());
+            }
+        }
+    }
+
+    for entity in removed_collider_mass_props.read() {
+        if let Some(((_, mut context_colliders, _, rigidbody_set), handle)) =
+            find_context(&mut context_writer, |context| {
+                context.1.entity2collider.get(&entity).copied()
+            })
+        {
+            if let Some(co) = context_colliders.colliders.get_mut(handle) {
+                // Matches the default `ColliderMassProperties::Density(1.0)`.
+                co.set_density(1.0);
+            }
+            if let Some(parent) = context_colliders.collider_parent(&rigidbody_set, entity) {
+                mass_modified.write(parent.into());
+            }
+        }
+    }
+
     // TODO: what about removing forces?
 }
 
@@ -1,7 +1,8 @@
 use crate::dynamics::RapierRigidBodyHandle;
 use crate::plugin::context::systemparams::RAPIER_CONTEXT_EXPECT_ERROR;
 use crate::plugin::context::{
-    DefaultRapierContext, RapierContextColliders, RapierContextEntityLink, RapierRigidBodySet,
+    DefaultRapierContext, RapierContextColliders, RapierContextEntityLink, RapierContextJoints,
+    RapierRigidBodySet,
 };
 use crate::plugin::{configuration::TimestepMode, RapierConfiguration};
 use crate::{dynamics::RigidBody, plugin::context::SimulationToRenderTime};
@@ -411,7 +412,7 @@ pub fn writeback_rigid_bodies(
         (With<RigidBody>, Without<RigidBodyDisabled>),
     >,
 ) {
-    for (handle, link, child_of, transform, mut interpolation, mut velocity, mut sleeping) in
+    for (handle, link, child_of, transform, mut interpolation, velocity, sleeping) in
         writeback.iter_mut()
     {
         let config = config
@@ -449,112 +450,280 @@ pub fn writeback_rigid_bodies(
                 }
             }
 
-            if let Some(mut transform) = transform {
-                // NOTE: Rapier's `RigidBody` doesn't know its own scale as it is encoded
-                //       directly within its collider, so we have to retrieve it from
-                //       the scale of its bevy transform.
-                interpolated_pos = interpolated_pos.with_scale(transform.scale);
-
-                // NOTE: we query the parent’s global transform here, which is a bit
-                //       unfortunate (performance-wise). An alternative would be to
-                //       deduce the parent’s global transform from the current entity’s
-                //       global transform. However, this makes it nearly impossible
-                //       (because of rounding errors) to predict the exact next value this
-                //       entity’s global transform will get after the next transform
-                //       propagation, which breaks our transform modification detection
-                //       that we do to detect if the user’s transform has to be written
-                //       into the rigid-body.
-                if let Some(parent_global_transform) =
-                    child_of.and_then(|c| global_transforms.get(c.parent()).ok())
-                {
-                    // We need to compute the new local transform such that:
-                    // curr_parent_global_transform * new_transform = interpolated_pos
-                    // new_transform = curr_parent_global_transform.inverse() * interpolated_pos
-                    let (inverse_parent_scale, inverse_parent_rotation, inverse_parent_translation) =
-                        parent_global_transform
-                            .affine()
-                            .inverse()
-                            .to_scale_rotation_translation();
-                    let new_rotation = inverse_parent_rotation * interpolated_pos.rotation;
-
-                    #[allow(unused_mut)] // mut is needed in 2D but not in 3D.
-                    let mut new_translation = inverse_parent_rotation
-                        * inverse_parent_scale
-                        * interpolated_pos.translation
-                        + inverse_parent_translation;
-
-                    // In 2D, preserve the transform `z` component that may have been set by the user
-                    #[cfg(feature = "dim2")]
-                    {
-                        new_translation.z = transform.translation.z;
-                    }
+            write_rigid_body_state(
+                handle,
+                rb,
+                interpolated_pos,
+                child_of.and_then(|c| global_transforms.get(c.parent()).ok()),
+                transform,
+                velocity,
+                sleeping,
+                &mut rigid_body_set.last_body_transform_set,
+            );
+        }
+    }
+}
 
-                    if transform.rotation != new_rotation
-                        || transform.translation != new_translation
-                    {
-                        // NOTE: we write the new value only if there was an
-                        //       actual change, in order to not trigger bevy’s
-                        //       change tracking when the values didn’t change.
-                        transform.rotation = new_rotation;
-                        transform.translation = new_translation;
-                    }
+/// System responsible for updating the handle components of the entities attached to contexts
+/// restored from a [`RapierSnapshot`](crate::plugin::RapierSnapshot).
+///
+/// The handles of the entities which weren't part of the snapshot (e.g. spawned after it was
+/// taken) are removed, so that new rapier objects are created for them instead of referring to
+/// objects missing from the restored context, or reused by it for other entities.
+#[allow(clippy::type_complexity)]
+pub fn sync_restored_handles(
+    mut commands: Commands,
+    contexts: Query<(
+        &RapierRigidBodySet,
+        &RapierContextColliders,
+        &RapierContextJoints,
+    )>,
+    mut bodies: Query<(Entity, &RapierContextEntityLink, &mut RapierRigidBodyHandle)>,
+    mut colliders: Query<(Entity, &RapierContextEntityLink, &mut RapierColliderHandle)>,
+    mut impulse_joints: Query<(
+        Entity,
+        &RapierContextEntityLink,
+        &mut RapierImpulseJointHandle,
+    )>,
+    mut multibody_joints: Query<(
+        Entity,
+        &RapierContextEntityLink,
+        &mut RapierMultibodyJointHandle,
+    )>,
+) {
+    if !contexts
+        .iter()
+        .any(|(set, _, _)| set.restored_from_snapshot)
+    {
+        return;
+    }
 
-                    // NOTE: we need to compute the result of the next transform propagation
-                    //       to make sure that our change detection for transforms is exact
-                    //       despite rounding errors.
-                    let new_global_transform = parent_global_transform.mul_transform(*transform);
-
-                    rigid_body_set
-                        .last_body_transform_set
-                        .insert(handle, new_global_transform);
-                } else {
-                    // In 2D, preserve the transform `z` component that may have been set by the user
-                    #[cfg(feature = "dim2")]
-                    {
-                        interpolated_pos.translation.z = transform.translation.z;
-                    }
+    for (entity, link, mut handle) in bodies.iter_mut() {
+        let Ok((rigidbody_set, _, _)) = contexts.get(link.0) else {
+            continue;
+        };
+        if !rigidbody_set.restored_from_snapshot {
+            continue;
+        }
+        match rigidbody_set.entity2body.get(&entity) {
+            Some(restored) => handle.0 = *restored,
+            None => {
+                commands.entity(entity).remove::<RapierRigidBodyHandle>();
+            }
+        }
+    }
 
-                    if transform.rotation != interpolated_pos.rotation
-                        || transform.translation != interpolated_pos.translation
-                    {
-                        // NOTE: we write the new value only if there was an
-                        //       actual change, in order to not trigger bevy’s
-                        //       change tracking when the values didn’t change.
-                        transform.rotation = interpolated_pos.rotation;
-                        transform.translation = interpolated_pos.translation;
-                    }
+    for (entity, link, mut handle) in colliders.iter_mut() {
+        let Ok((rigidbody_set, context_colliders, _)) = contexts.get(link.0) else {
+            continue;
+        };
+        if !rigidbody_set.restored_from_snapshot {
+            continue;
+        }
+        match context_colliders.entity2collider.get(&entity) {
+            Some(restored) => handle.0 = *restored,
+            None => {
+                commands.entity(entity).remove::<RapierColliderHandle>();
+            }
+        }
+    }
 
-                    rigid_body_set
-                        .last_body_transform_set
-                        .insert(handle, GlobalTransform::from(interpolated_pos));
-                }
+    for (entity, link, mut handle) in impulse_joints.iter_mut() {
+        let Ok((rigidbody_set, _, joints)) = contexts.get(link.0) else {
+            continue;
+        };
+        if !rigidbody_set.restored_from_snapshot {
+            continue;
+        }
+        match joints.entity2impulse_joint.get(&entity) {
+            Some(restored) => handle.0 = *restored,
+            None => {
+                commands.entity(entity).remove::<RapierImpulseJointHandle>();
             }
+        }
+    }
 
-            if let Some(velocity) = &mut velocity {
-                let new_vel = Velocity {
-                    linvel: (*rb.linvel()).into(),
-                    #[cfg(feature = "dim3")]
-                    angvel: (*rb.angvel()).into(),
-                    #[cfg(feature = "dim2")]
-                    angvel: rb.angvel(),
-                };
+    for (entity, link, mut handle) in multibody_joints.iter_mut() {
+        let Ok((rigidbody_set, _, joints)) = contexts.get(link.0) else {
+            continue;
+        };
+        if !rigidbody_set.restored_from_snapshot {
+            continue;
+        }
+        match joints.entity2multibody_joint.get(&entity) {
+            Some(restored) => handle.0 = *restored,
+            None => {
+                commands
+                    .entity(entity)
+                    .remove::<RapierMultibodyJointHandle>();
+            }
+        }
+    }
+}
+
+/// System responsible for writing the state of the rigid-bodies of contexts restored from a
+/// [`RapierSnapshot`](crate::plugin::RapierSnapshot) into our `bevy_rapier` components.
+///
+/// This runs before user changes are applied to the simulation, so that the restored state isn't
+/// overwritten by the (now outdated) transforms and velocities of the bevy components.
+pub fn writeback_restored_rigid_bodies(
+    mut rigid_body_sets: Query<&mut RapierRigidBodySet>,
+    global_transforms: Query<&GlobalTransform>,
+    mut writeback: Query<
+        RigidBodyWritebackComponents,
+        (With<RigidBody>, Without<RigidBodyDisabled>),
+    >,
+) {
+    if !rigid_body_sets.iter().any(|set| set.restored_from_snapshot) {
+        return;
+    }
+
+    for (handle, link, child_of, transform, interpolation, velocity, sleeping) in
+        writeback.iter_mut()
+    {
+        let Ok(mut rigid_body_set) = rigid_body_sets.get_mut(link.0) else {
+            continue;
+        };
+        if !rigid_body_set.restored_from_snapshot {
+            continue;
+        }
+        let rigid_body_set = rigid_body_set.bypass_change_detection();
+
+        if let Some(mut interpolation) = interpolation {
+            // The interpolation range refers to positions which don't exist anymore.
+            interpolation.start = None;
+            interpolation.end = None;
+        }
+
+        if let Some(rb) = rigid_body_set.bodies.get(handle.0) {
+            write_rigid_body_state(
+                handle.0,
+                rb,
+                utils::iso_to_transform(rb.position()),
+                child_of.and_then(|c| global_transforms.get(c.parent()).ok()),
+                transform,
+                velocity,
+                sleeping,
+                &mut rigid_body_set.last_body_transform_set,
+            );
+        }
+    }
+
+    for mut rigid_body_set in rigid_body_sets.iter_mut() {
+        if rigid_body_set.restored_from_snapshot {
+            rigid_body_set.restored_from_snapshot = false;
+        }
+    }
+}
 
+/// Writes the position, velocity and sleeping state of `rb` into its bevy components, and records
+/// the resulting global transform for change detection.
+#[allow(clippy::too_many_arguments)]
+fn write_rigid_body_state(
+    handle: RigidBodyHandle,
+    rb: &rapier::dynamics::RigidBody,
+    mut interpolated_pos: Transform,
+    parent_global_transform: Option<&GlobalTransform>,
+    transform: Option<Mut<Transform>>,
+    velocity: Option<Mut<Velocity>>,
+    sleeping: Option<Mut<Sleeping>>,
+    last_body_transform_set: &mut HashMap<RigidBodyHandle, GlobalTransform>,
+) {
+    if let Some(mut transform) = transform {
+        // NOTE: Rapier's `RigidBody` doesn't know its own scale as it is encoded
+        //       directly within its collider, so we have to retrieve it from
+        //       the scale of its bevy transform.
+        interpolated_pos = interpolated_pos.with_scale(transform.scale);
+
+        // NOTE: we query the parent’s global transform here, which is a bit
+        //       unfortunate (performance-wise). An alternative would be to
+        //       deduce the parent’s global transform from the current entity’s
+        //       global transform. However, this makes it nearly impossible
+        //       (because of rounding errors) to predict the exact next value this
+        //       entity’s global transform will get after the next transform
+        //       propagation, which breaks our transform modification detection
+        //       that we do to detect if the user’s transform has to be written
+        //       into the rigid-body.
+        if let Some(parent_global_transform) = parent_global_transform {
+            // We need to compute the new local transform such that:
+            // curr_parent_global_transform * new_transform = interpolated_pos
+            // new_transform = curr_parent_global_transform.inverse() * interpolated_pos
+            let (inverse_parent_scale, inverse_parent_rotation, inverse_parent_translation) =
+                parent_global_transform
+                    .affine()
+                    .inverse()
+                    .to_scale_rotation_translation();
+            let new_rotation = inverse_parent_rotation * interpolated_pos.rotation;
+
+            #[allow(unused_mut)] // mut is needed in 2D but not in 3D.
+            let mut new_translation =
+                inverse_parent_rotation * inverse_parent_scale * interpolated_pos.translation
+                    + inverse_parent_translation;
+
+            // In 2D, preserve the transform `z` component that may have been set by the user
+            #[cfg(feature = "dim2")]
+            {
+                new_translation.z = transform.translation.z;
+            }
+
+            if transform.rotation != new_rotation || transform.translation != new_translation {
                 // NOTE: we write the new value only if there was an
                 //       actual change, in order to not trigger bevy’s
                 //       change tracking when the values didn’t change.
-                if **velocity != new_vel {
-                    **velocity = new_vel;
-                }
+                transform.rotation = new_rotation;
+                transform.translation = new_translation;
+            }
+
+            // NOTE: we need to compute the result of the next transform propagation
+            //       to make sure that our change detection for transforms is exact
+            //       despite rounding errors.
+            let new_global_transform = parent_global_transform.mul_transform(*transform);
+
+            last_body_transform_set.insert(handle, new_global_transform);
+        } else {
+            // In 2D, preserve the transform `z` component that may have been set by the user
+            #[cfg(feature = "dim2")]
+            {
+                interpolated_pos.translation.z = transform.translation.z;
             }
 
-            if let Some(sleeping) = &mut sleeping {
+            if transform.rotation != interpolated_pos.rotation
+                || transform.translation != interpolated_pos.translation
+            {
                 // NOTE: we write the new value only if there was an
                 //       actual change, in order to not trigger bevy’s
                 //       change tracking when the values didn’t change.
-                if sleeping.sleeping != rb.is_sleeping() {
-                    sleeping.sleeping = rb.is_sleeping();
-                }
+                transform.rotation = interpolated_pos.rotation;
+                transform.translation = interpolated_pos.translation;
             }
+
+            last_body_transform_set.insert(handle, GlobalTransform::from(interpolated_pos));
+        }
+    }
+
+    if let Some(mut velocity) = velocity {
+        let new_vel = Velocity {
+            linvel: (*rb.linvel()).into(),
+            #[cfg(feature = "dim3")]
+            angvel: (*rb.angvel()).into(),
+            #[cfg(feature = "dim2")]
+            angvel: rb.angvel(),
+        };
+
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if *velocity != new_vel {
+            *velocity = new_vel;
+        }
+    }
+
+    if let Some(mut sleeping) = sleeping {
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if sleeping.sleeping != rb.is_sleeping() {
+            sleeping.sleeping = rb.is_sleeping();
         }
     }
 }
@@ -1,6 +1,9 @@
 use crate::math::Real;
-use bevy::reflect::reflect_remote;
+use bevy::reflect::{reflect_remote, FromReflect, PartialReflect};
+use rapier::control::{CharacterAutostep, CharacterLength};
 use rapier::dynamics::IntegrationParameters;
+use rapier::parry::query::ShapeCastStatus;
+use rapier::pipeline::QueryFilterFlags;
 use std::num::NonZeroUsize;
 
 #[reflect_remote(IntegrationParameters)]
@@ -98,3 +101,94 @@ pub struct IntegrationParametersWrapper {
     /// Maximum number of substeps performed by the  solver (default: `1`).
     pub max_ccd_substeps: usize,
 }
+
+#[reflect_remote(CharacterLength)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// A length measure used for various options of a character controller.
+pub enum CharacterLengthWrapper {
+    /// The length is specified relative to some of the character shape’s size.
+    Relative(Real),
+    /// The length is specified as an absolute value, independent from the character shape’s size.
+    Absolute(Real),
+}
+
+#[reflect_remote(Option<CharacterLength>)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// An optional [`CharacterLength`].
+pub enum OptionCharacterLengthWrapper {
+    /// No length.
+    None,
+    /// Some length.
+    Some(#[reflect(remote = CharacterLengthWrapper)] CharacterLength),
+}
+
+#[reflect_remote(CharacterAutostep)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// Configuration for the auto-stepping character controller feature.
+pub struct CharacterAutostepWrapper {
+    /// The maximum step height a character can automatically step over.
+    #[reflect(remote = CharacterLengthWrapper)]
+    pub max_height: CharacterLength,
+    /// The minimum width of free space that must be available after stepping on a stair.
+    #[reflect(remote = CharacterLengthWrapper)]
+    pub min_width: CharacterLength,
+    /// Can the character automatically step over dynamic bodies too?
+    pub include_dynamic_bodies: bool,
+}
+
+#[reflect_remote(Option<CharacterAutostep>)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+/// An optional [`CharacterAutostep`].
+pub enum OptionCharacterAutostepWrapper {
+    /// Auto-stepping is disabled.
+    None,
+    /// Auto-stepping is enabled.
+    Some(#[reflect(remote = CharacterAutostepWrapper)] CharacterAutostep),
+}
+
+#[reflect_remote(QueryFilterFlags)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[reflect(opaque, from_reflect = false)]
+#[reflect(Debug, PartialEq)]
+/// Flags for excluding whole sets of colliders from a scene query.
+///
+/// With the `serde-serialize` feature, these flags are serialized as their bits.
+pub struct QueryFilterFlagsWrapper;
+
+// The derived implementation downcasts to the wrapper, but the reflected value is the remote type.
+impl FromReflect for QueryFilterFlagsWrapper {
+    fn from_reflect(reflect: &dyn PartialReflect) -> Option<Self> {
+        reflect
+            .try_downcast_ref::<QueryFilterFlags>()
+            .map(|flags| Self(*flags))
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl serde::Serialize for QueryFilterFlagsWrapper {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.bits().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de> serde::Deserialize<'de> for QueryFilterFlagsWrapper {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(|bits| Self(QueryFilterFlags::from_bits_truncate(bits)))
+    }
+}
+
+#[reflect_remote(ShapeCastStatus)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The status of the time-of-impact computation algorithm.
+pub enum ShapeCastStatusWrapper {
+    /// The shape-casting algorithm ran out of iterations before achieving convergence.
+    OutOfIterations,
+    /// The shape-casting algorithm converged successfully.
+    Converged,
+    /// Something went wrong during the shape-casting, likely due to numerical instabilities.
+    Failed,
+    /// The two shape already overlap, or are separated by a distance smaller than the target
+    /// distance at the time 0.
+    PenetratingOrWithinTargetDist,
+}
@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use rapier::geometry::{ColliderHandle, ColliderSet, NarrowPhase};
+use rapier::math::{Point, Real};
+use rapier::prelude::RigidBodySet;
+
+use crate::dynamics::{ExternalForce, ReadMassProperties};
+use crate::math::Vect;
+
+#[cfg(doc)]
+use crate::prelude::{Collider, RigidBody, Sensor};
+#[cfg(doc)]
+use crate::render::{ColliderDebugColor, DebugRenderContext, DebugRenderStyle};
+
+/// The colors of the colliders depending on their state and the state of their rigid-body.
+///
+/// When set on the [`DebugRenderContext`], these colors replace the ones of the
+/// [`DebugRenderStyle`] for the colliders in the corresponding states, but not the colors set with
+/// a [`ColliderDebugColor`] component. States whose color is `None` are ignored. When a collider is
+/// in several states, the color of the first one in the order of the fields is used.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct ColliderStateColors {
+    /// The color of the disabled colliders, and of the colliders of disabled rigid-bodies.
+    pub disabled: Option<Hsla>,
+    /// The color of the colliders with at least one active contact, or intersecting a [`Sensor`].
+    pub colliding: Option<Hsla>,
+    /// The color of the colliders of sleeping rigid-bodies.
+    pub sleeping: Option<Hsla>,
+    /// The color of the colliders of rigid-bodies with an active continuous collision detection.
+    pub ccd_active: Option<Hsla>,
+    /// The color of the [`Sensor`] colliders.
+    pub sensor: Option<Hsla>,
+    /// The color of the colliders of kinematic rigid-bodies.
+    pub kinematic: Option<Hsla>,
+    /// The color of the colliders of fixed rigid-bodies, and of the colliders without rigid-body.
+    pub fixed: Option<Hsla>,
+}
+
+impl ColliderStateColors {
+    /// A distinct color for each state.
+    pub fn all() -> Self {
+        Self {
+            disabled: Some(Hsla::hsl(0.0, 0.0, 0.5)),
+            colliding: Some(Hsla::hsl(0.0, 1.0, 0.5)),
+            sleeping: Some(Hsla::hsl(220.0, 0.8, 0.6)),
+            ccd_active: Some(Hsla::hsl(300.0, 1.0, 0.5)),
+            sensor: Some(Hsla::hsl(50.0, 1.0, 0.5)),
+            kinematic: Some(Hsla::hsl(180.0, 1.0, 0.4)),
+            fixed: Some(Hsla::hsl(30.0, 0.5, 0.4)),
+        }
+    }
+
+    /// The color of the given collider, or `None` if none of its states have a color.
+    pub(crate) fn collider_color(
+        &self,
+        handle: ColliderHandle,
+        colliders: &ColliderSet,
+        bodies: &RigidBodySet,
+        narrow_phase: &NarrowPhase,
+    ) -> Option<Hsla> {
+        let collider = colliders.get(handle)?;
+        let body = collider.parent().and_then(|parent| bodies.get(parent));
+        let colliding = || {
+            narrow_phase
+                .contact_pairs_with(handle)
+                .any(|pair| pair.has_any_active_contact)
+                || narrow_phase
+                    .intersection_pairs_with(handle)
+                    .any(|(_, _, intersecting)| intersecting)
+        };
+
+        [
+            (
+                self.disabled,
+                !collider.is_enabled() || body.is_some_and(|body| !body.is_enabled()),
+            ),
+            (self.colliding, self.colliding.is_some() && colliding()),
+            (self.sleeping, body.is_some_and(|body| body.is_sleeping())),
+            (
+                self.ccd_active,
+                body.is_some_and(|body| body.is_ccd_active()),
+            ),
+            (self.sensor, collider.is_sensor()),
+            (self.kinematic, body.is_some_and(|body| body.is_kinematic())),
+            (self.fixed, body.is_none_or(|body| body.is_fixed())),
+        ]
+        .into_iter()
+        .find_map(|(color, in_state)| color.filter(|_| in_state))
+    }
+}
+
+/// Vectors and points rendered for each rigid-body by the debug-renderer, in addition to the
+/// elements selected by the [`DebugRenderMode`](crate::render::DebugRenderMode).
+///
+/// Overlays whose color is `None` aren't rendered.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct RigidBodyDebugOverlays {
+    /// The color of the linear velocity, rendered from the center of mass of the rigid-body.
+    pub linear_velocity: Option<Hsla>,
+    /// The color of the angular velocity.
+    ///
+    /// In 3D, it is rendered as a vector along the rotation axis, from the center of mass of the
+    /// rigid-body. In 2D, it is rendered as an arc around the center of mass, with a radius of
+    /// `center_of_mass_size`.
+    pub angular_velocity: Option<Hsla>,
+    /// The color of the linear force of the [`ExternalForce`] applied to the rigid-body.
+    pub external_force: Option<Hsla>,
+    /// The color of the center of mass of the rigid-bodies with a [`ReadMassProperties`]
+    /// component, rendered as a cross.
+    pub center_of_mass: Option<Hsla>,
+    /// The length of the rendered velocities, per unit of velocity.
+    pub velocity_scale: Real,
+    /// The length of the rendered forces, per unit of force.
+    pub force_scale: Real,
+    /// The half-size of the cross rendered at the center of mass.
+    pub center_of_mass_size: Real,
+}
+
+impl Default for RigidBodyDebugOverlays {
+    fn default() -> Self {
+        Self {
+            linear_velocity: None,
+            angular_velocity: None,
+            external_force: None,
+            center_of_mass: None,
+            velocity_scale: 1.0,
+            force_scale: 1.0,
+            #[cfg(feature = "dim2")]
+            center_of_mass_size: 10.0,
+            #[cfg(feature = "dim3")]
+            center_of_mass_size: 0.2,
+        }
+    }
+}
+
+impl RigidBodyDebugOverlays {
+    /// All the overlays, with a distinct color for each of them.
+    pub fn all() -> Self {
+        Self {
+            linear_velocity: Some(Hsla::hsl(120.0, 1.0, 0.4)),
+            angular_velocity: Some(Hsla::hsl(270.0, 1.0, 0.6)),
+            external_force: Some(Hsla::hsl(15.0, 1.0, 0.5)),
+            center_of_mass: Some(Hsla::hsl(0.0, 0.0, 0.1)),
+            ..Default::default()
+        }
+    }
+
+    /// Whether no overlay is rendered.
+    pub fn is_empty(&self) -> bool {
+        self.linear_velocity.is_none()
+            && self.angular_velocity.is_none()
+            && self.external_force.is_none()
+            && self.center_of_mass.is_none()
+    }
+
+    /// Renders the overlays of the given rigid-bodies as lines.
+    pub(crate) fn render(
+        &self,
+        draw: &mut dyn FnMut(Vec3, Vec3, Color),
+        bodies: &RigidBodySet,
+        external_forces: &Query<&ExternalForce>,
+        mass_properties: &Query<&ReadMassProperties>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        for (_, body) in bodies.iter() {
+            let entity = Entity::from_bits(body.user_data as u64);
+            let center = to_vec3((*body.center_of_mass()).into());
+
+            if let Some(color) = self.linear_velocity {
+                let linvel = to_vec3((*body.linvel()).into()) * self.velocity_scale;
+                draw(center, center + linvel, color.into());
+            }
+
+            if let Some(color) = self.angular_velocity {
+                #[cfg(feature = "dim2")]
+                self.render_angular_velocity(draw, center, body.angvel(), color.into());
+                #[cfg(feature = "dim3")]
+                {
+                    let angvel = Vect::from(*body.angvel()) * self.velocity_scale;
+                    draw(center, center + angvel, color.into());
+                }
+            }
+
+            if let Some(color) = self.external_force {
+                if let Ok(external_force) = external_forces.get(entity) {
+                    let force = to_vec3(external_force.force) * self.force_scale;
+                    draw(center, center + force, color.into());
+                }
+            }
+
+            if let Some(color) = self.center_of_mass {
+                if let Ok(mass_properties) = mass_properties.get(entity) {
+                    let local_center = mass_properties.get().local_center_of_mass;
+                    let center = to_vec3((body.position() * Point::from(local_center)).into());
+                    draw_cross(draw, center, self.center_of_mass_size, color.into());
+                }
+            }
+        }
+    }
+
+    /// Renders the angle traveled by a 2D rigid-body, scaled by `velocity_scale`, as an arc.
+    #[cfg(feature = "dim2")]
+    fn render_angular_velocity(
+        &self,
+        draw: &mut dyn FnMut(Vec3, Vec3, Color),
+        center: Vec3,
+        angvel: Real,
+        color: Color,
+    ) {
+        const SEGMENTS: usize = 16;
+        let angle =
+            (angvel * self.velocity_scale).clamp(-std::f32::consts::TAU, std::f32::consts::TAU);
+        let point = |i: usize| {
+            let (sin, cos) = (angle * i as Real / SEGMENTS as Real).sin_cos();
+            center + Vec3::new(cos, sin, 0.0) * self.center_of_mass_size
+        };
+        for i in 0..SEGMENTS {
+            draw(point(i), point(i + 1), color);
+        }
+    }
+}
+
+#[cfg(feature = "dim2")]
+pub(super) fn to_vec3(v: Vect) -> Vec3 {
+    v.extend(0.0)
+}
+
+#[cfg(feature = "dim3")]
+pub(super) fn to_vec3(v: Vect) -> Vec3 {
+    v
+}
+
+/// Draws a cross of the given half-size, aligned with the world axes.
+pub(super) fn draw_cross(
+    draw: &mut dyn FnMut(Vec3, Vec3, Color),
+    center: Vec3,
+    size: Real,
+    color: Color,
+) {
+    #[cfg(feature = "dim2")]
+    let axes = [Vec3::X, Vec3::Y];
+    #[cfg(feature = "dim3")]
+    let axes = [Vec3::X, Vec3::Y, Vec3::Z];
+    for axis in axes {
+        draw(center - axis * size, center + axis * size, color);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rapier::prelude::{ColliderBuilder, RigidBodyBuilder};
+
+    #[test]
+    fn collider_state_colors_follow_priorities() {
+        let mut bodies = RigidBodySet::new();
+        let mut colliders = ColliderSet::new();
+        let narrow_phase = NarrowPhase::new();
+
+        let dynamic = bodies.insert(RigidBodyBuilder::dynamic().sleeping(true));
+        let sleeping_sensor = colliders.insert_with_parent(
+            ColliderBuilder::ball(0.5).sensor(true),
+            dynamic,
+            &mut bodies,
+        );
+        let kinematic = bodies.insert(RigidBodyBuilder::kinematic_position_based());
+        let kinematic_collider =
+            colliders.insert_with_parent(ColliderBuilder::ball(0.5), kinematic, &mut bodies);
+        let disabled = colliders.insert(ColliderBuilder::ball(0.5).enabled(false));
+        let free = colliders.insert(ColliderBuilder::ball(0.5));
+
+        let colors = ColliderStateColors::all();
+        let color = |handle| colors.collider_color(handle, &colliders, &bodies, &narrow_phase);
+        assert_eq!(color(sleeping_sensor), colors.sleeping);
+        assert_eq!(color(kinematic_collider), colors.kinematic);
+        assert_eq!(color(disabled), colors.disabled);
+        assert_eq!(color(free), colors.fixed);
+
+        let colors = ColliderStateColors {
+            sleeping: None,
+            ..ColliderStateColors::all()
+        };
+        assert_eq!(
+            colors.collider_color(sleeping_sensor, &colliders, &bodies, &narrow_phase),
+            colors.sensor
+        );
+        assert_eq!(
+            ColliderStateColors::default().collider_color(free, &colliders, &bodies, &narrow_phase),
+            None
+        );
+    }
+}
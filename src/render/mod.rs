@@ -1,13 +1,30 @@
+pub use self::body_state::{ColliderStateColors, RigidBodyDebugOverlays};
+pub use self::queries::{CharacterControllerDebugRender, SceneQueryDebugRender};
+
+use self::queries::ShapeRenderer;
+
+use crate::control::{KinematicCharacterController, KinematicCharacterControllerOutput};
+use crate::dynamics::{ExternalForce, ReadMassProperties};
+use crate::geometry::Collider;
 use crate::plugin::context::{
-    RapierContextColliders, RapierContextJoints, RapierContextSimulation, RapierRigidBodySet,
+    LoggedQuery, RapierContextColliders, RapierContextEntityLink, RapierContextJoints,
+    RapierContextSimulation, RapierQueryPipeline, RapierRigidBodySet,
 };
+use bevy::gizmos::config::GizmoConfigGroup;
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
 use bevy::transform::TransformSystem;
+use rapier::geometry::NarrowPhase;
 use rapier::math::{Point, Real};
 use rapier::pipeline::{DebugRenderBackend, DebugRenderObject, DebugRenderPipeline};
 pub use rapier::pipeline::{DebugRenderMode, DebugRenderStyle};
+use rapier::prelude::RigidBodySet;
 use std::fmt::Debug;
 
+mod body_state;
+mod queries;
+
 #[cfg(doc)]
 use crate::prelude::Collider;
 
@@ -44,6 +61,18 @@ pub struct RapierDebugRenderPlugin {
     /// Flags to select what part of physics scene is rendered (by default
     /// everything is rendered).
     pub mode: DebugRenderMode,
+    /// The colors of the colliders depending on their state (by default
+    /// the colors of the `style` are used).
+    pub collider_state_colors: ColliderStateColors,
+    /// The vectors and points rendered for each rigid-body (by default
+    /// none are rendered).
+    pub overlays: RigidBodyDebugOverlays,
+    /// How the logged scene queries are rendered (by default they
+    /// aren't logged nor rendered).
+    pub scene_queries: SceneQueryDebugRender,
+    /// How the character controllers are rendered (by default they
+    /// aren't rendered).
+    pub character_controllers: CharacterControllerDebugRender,
 }
 
 #[allow(clippy::derivable_impls)] // The 3D impl can be derived, but not the 2D impl.
@@ -58,6 +87,10 @@ impl Default for RapierDebugRenderPlugin {
                 ..Default::default()
             },
             mode: DebugRenderMode::default(),
+            collider_state_colors: ColliderStateColors::default(),
+            overlays: RigidBodyDebugOverlays::default(),
+            scene_queries: SceneQueryDebugRender::default(),
+            character_controllers: CharacterControllerDebugRender::default(),
         }
     }
     #[cfg(feature = "dim3")]
@@ -67,6 +100,10 @@ impl Default for RapierDebugRenderPlugin {
             default_collider_debug: ColliderDebug::AlwaysRender,
             style: DebugRenderStyle::default(),
             mode: DebugRenderMode::default(),
+            collider_state_colors: ColliderStateColors::default(),
+            overlays: RigidBodyDebugOverlays::default(),
+            scene_queries: SceneQueryDebugRender::default(),
+            character_controllers: CharacterControllerDebugRender::default(),
         }
     }
 }
@@ -77,11 +114,44 @@ impl RapierDebugRenderPlugin {
         self.enabled = false;
         self
     }
+
+    /// Initialize the render plugin such that the colliders are colored depending on their state.
+    pub fn with_collider_state_colors(
+        mut self,
+        collider_state_colors: ColliderStateColors,
+    ) -> Self {
+        self.collider_state_colors = collider_state_colors;
+        self
+    }
+
+    /// Initialize the render plugin such that the given overlays are rendered for each rigid-body.
+    pub fn with_overlays(mut self, overlays: RigidBodyDebugOverlays) -> Self {
+        self.overlays = overlays;
+        self
+    }
+
+    /// Initialize the render plugin such that the scene queries are logged and rendered.
+    pub fn with_scene_queries(mut self, scene_queries: SceneQueryDebugRender) -> Self {
+        self.scene_queries = scene_queries;
+        self
+    }
+
+    /// Initialize the render plugin such that the character controllers are rendered.
+    pub fn with_character_controllers(
+        mut self,
+        character_controllers: CharacterControllerDebugRender,
+    ) -> Self {
+        self.character_controllers = character_controllers;
+        self
+    }
 }
 
 /// Context to control some aspect of the debug-renderer after initialization.
-#[derive(Resource, Reflect)]
-#[reflect(Resource)]
+///
+/// The [`DebugRenderContext`] resource applies to every rapier context. Insert this component on
+/// the entity of a rapier context to override it for this context only.
+#[derive(Resource, Component, Reflect)]
+#[reflect(Resource, Component)]
 pub struct DebugRenderContext {
     /// Is the debug-rendering currently enabled?
     pub enabled: bool,
@@ -93,6 +163,15 @@ pub struct DebugRenderContext {
     /// to modify the set of rendered elements, and modify the default coloring rules.
     #[reflect(ignore)]
     pub pipeline: DebugRenderPipeline,
+    /// The colors of the colliders depending on their state, used instead of the colors of the
+    /// pipeline style.
+    pub collider_state_colors: ColliderStateColors,
+    /// The vectors and points rendered for each rigid-body.
+    pub overlays: RigidBodyDebugOverlays,
+    /// How the scene queries are logged and rendered.
+    pub scene_queries: SceneQueryDebugRender,
+    /// How the character controllers are rendered.
+    pub character_controllers: CharacterControllerDebugRender,
 }
 
 impl Default for DebugRenderContext {
@@ -101,20 +180,49 @@ impl Default for DebugRenderContext {
             enabled: true,
             default_collider_debug: ColliderDebug::AlwaysRender,
             pipeline: DebugRenderPipeline::default(),
+            collider_state_colors: ColliderStateColors::default(),
+            overlays: RigidBodyDebugOverlays::default(),
+            scene_queries: SceneQueryDebugRender::default(),
+            character_controllers: CharacterControllerDebugRender::default(),
         }
     }
 }
 
+/// The gizmo configuration group of the debug-renderer.
+///
+/// Its [`GizmoConfig`] can be modified through the [`GizmoConfigStore`] to change the line width,
+/// the depth bias and the [`RenderLayers`] of the debug lines, or to hide them.
+///
+/// The debug lines of a rapier context whose entity has a [`RenderLayers`] component are rendered
+/// with these render layers instead, so that they only show in the matching cameras.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+#[reflect(Default)]
+pub struct RapierDebugGizmos;
+
+/// The retained gizmo rendering the debug lines of the rapier context with a [`RenderLayers`]
+/// component.
+#[derive(Component)]
+struct RapierContextDebugGizmo(Entity);
+
 impl Plugin for RapierDebugRenderPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<DebugRenderContext>();
         app.register_type::<ColliderDebug>();
         app.register_type::<ColliderDebugColor>();
+        app.register_type::<ColliderStateColors>();
+        app.register_type::<RigidBodyDebugOverlays>();
+        app.register_type::<SceneQueryDebugRender>();
+        app.register_type::<CharacterControllerDebugRender>();
+        app.init_gizmo_group::<RapierDebugGizmos>();
 
         app.insert_resource(DebugRenderContext {
             enabled: self.enabled,
             default_collider_debug: self.default_collider_debug,
             pipeline: DebugRenderPipeline::new(self.style, self.mode),
+            collider_state_colors: self.collider_state_colors,
+            overlays: self.overlays,
+            scene_queries: self.scene_queries,
+            character_controllers: self.character_controllers,
         })
         .add_systems(
             PostUpdate,
@@ -123,25 +231,37 @@ impl Plugin for RapierDebugRenderPlugin {
     }
 }
 
-struct BevyLinesRenderBackend<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p> {
+struct BevyLinesRenderBackend<'world, 'state, 'a, 'c, 'd, 'v, 'p> {
     custom_colors: &'c Query<'world, 'state, &'a ColliderDebugColor>,
     default_collider_debug: ColliderDebug,
     override_visibility: &'v Query<'world, 'state, &'a ColliderDebug>,
     context_colliders: &'d RapierContextColliders,
-    gizmos: &'p mut Gizmos<'world2, 'state2>,
+    bodies: &'d RigidBodySet,
+    narrow_phase: &'d NarrowPhase,
+    collider_state_colors: ColliderStateColors,
+    /// Draws a line into the immediate or retained gizmos of the context.
+    draw: &'p mut dyn FnMut(Vec3, Vec3, Color),
 }
 
-impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p>
-    BevyLinesRenderBackend<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p>
+impl<'world, 'state, 'a, 'c, 'd, 'v, 'p>
+    BevyLinesRenderBackend<'world, 'state, 'a, 'c, 'd, 'v, 'p>
 {
     fn object_color(&self, object: DebugRenderObject, default: [f32; 4]) -> [f32; 4] {
         let color = match object {
             DebugRenderObject::Collider(h, ..) => {
-                self.context_colliders.colliders.get(h).and_then(|co| {
+                let custom_color = self.context_colliders.colliders.get(h).and_then(|co| {
                     self.custom_colors
                         .get(Entity::from_bits(co.user_data as u64))
                         .map(|co| co.0)
                         .ok()
+                });
+                custom_color.or_else(|| {
+                    self.collider_state_colors.collider_color(
+                        h,
+                        &self.context_colliders.colliders,
+                        self.bodies,
+                        self.narrow_phase,
+                    )
                 })
             }
             _ => None,
@@ -171,8 +291,8 @@ impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p>
     }
 }
 
-impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p> DebugRenderBackend
-    for BevyLinesRenderBackend<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p>
+impl<'world, 'state, 'a, 'c, 'd, 'v, 'p> DebugRenderBackend
+    for BevyLinesRenderBackend<'world, 'state, 'a, 'c, 'd, 'v, 'p>
 {
     #[cfg(feature = "dim2")]
     fn draw_line(
@@ -187,7 +307,7 @@ impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p> DebugRenderBackend
         }
 
         let color = self.object_color(object, color);
-        self.gizmos.line(
+        (self.draw)(
             [a.x, a.y, 0.0].into(),
             [b.x, b.y, 0.0].into(),
             Color::hsla(color[0], color[1], color[2], color[3]),
@@ -207,7 +327,7 @@ impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p> DebugRenderBackend
         }
 
         let color = self.object_color(object, color);
-        self.gizmos.line(
+        (self.draw)(
             [a.x, a.y, a.z].into(),
             [b.x, b.y, b.z].into(),
             Color::hsla(color[0], color[1], color[2], color[3]),
@@ -215,39 +335,272 @@ impl<'world, 'state, 'world2, 'state2, 'a, 'c, 'd, 'v, 'p> DebugRenderBackend
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn debug_render_scene<'a>(
-    rapier_context: Query<(
-        &RapierContextSimulation,
-        &RapierContextColliders,
-        &RapierContextJoints,
-        &RapierRigidBodySet,
-    )>,
+    mut commands: Commands,
+    mut rapier_context: Query<
+        (
+            Entity,
+            &RapierContextSimulation,
+            &RapierContextColliders,
+            &RapierContextJoints,
+            &RapierRigidBodySet,
+            Option<&mut DebugRenderContext>,
+            Option<&RenderLayers>,
+            Option<&mut RapierQueryPipeline>,
+        ),
+        Without<RapierContextDebugGizmo>,
+    >,
     mut render_context: ResMut<DebugRenderContext>,
-    mut gizmos: Gizmos,
+    mut gizmos: Gizmos<RapierDebugGizmos>,
+    mut gizmo_assets: ResMut<Assets<GizmoAsset>>,
+    mut context_gizmos: Query<(
+        Entity,
+        &RapierContextDebugGizmo,
+        &mut Gizmo,
+        &mut RenderLayers,
+    )>,
     custom_colors: Query<&'a ColliderDebugColor>,
     override_visibility: Query<&'a ColliderDebug>,
+    external_forces: Query<&ExternalForce>,
+    mass_properties: Query<&ReadMassProperties>,
+    character_controllers: Query<(
+        &KinematicCharacterController,
+        Option<&KinematicCharacterControllerOutput>,
+        Option<&Collider>,
+        &GlobalTransform,
+        &RapierContextEntityLink,
+    )>,
+    mut logged_queries: Local<HashMap<Entity, Vec<(LoggedQuery, u32)>>>,
 ) {
-    if !render_context.enabled {
-        return;
-    }
-    for (rapier_context, rapier_context_colliders, joints, rigidbody_set) in rapier_context.iter() {
-        let mut backend = BevyLinesRenderBackend {
-            custom_colors: &custom_colors,
-            default_collider_debug: render_context.default_collider_debug,
-            override_visibility: &override_visibility,
-            context_colliders: rapier_context_colliders,
-            gizmos: &mut gizmos,
+    let mut context_gizmos: HashMap<_, _> = context_gizmos
+        .iter_mut()
+        .map(|(entity, context, gizmo, layers)| (context.0, (entity, gizmo, layers)))
+        .collect();
+    let (line_config, depth_bias) = (gizmos.config.line.clone(), gizmos.config.depth_bias);
+    let gizmos_enabled = gizmos.config.enabled;
+    let mut shapes = ShapeRenderer::default();
+
+    for (
+        context_entity,
+        rapier_context,
+        rapier_context_colliders,
+        joints,
+        rigidbody_set,
+        context_render_context,
+        render_layers,
+        query_pipeline,
+    ) in rapier_context.iter_mut()
+    {
+        let render_context = match context_render_context {
+            Some(render_context) => render_context.into_inner(),
+            None => &mut *render_context,
+        };
+        let scene_queries = render_context.scene_queries;
+        let queries = logged_queries.entry(context_entity).or_default();
+        if let Some(mut query_pipeline) = query_pipeline {
+            if render_context.enabled && gizmos_enabled && scene_queries.frames > 0 {
+                if query_pipeline.query_log().is_none() {
+                    query_pipeline.enable_query_log();
+                }
+                let logged = query_pipeline.query_log().map(|log| log.drain());
+                queries.extend(
+                    logged
+                        .into_iter()
+                        .flatten()
+                        .map(|query| (query, scene_queries.frames)),
+                );
+            } else if query_pipeline.query_log().is_some() {
+                query_pipeline.disable_query_log();
+            }
+        }
+        if !render_context.enabled || !gizmos_enabled {
+            queries.clear();
+            continue;
+        }
+
+        let mut render = |draw: &mut dyn FnMut(Vec3, Vec3, Color)| {
+            let mut backend = BevyLinesRenderBackend {
+                custom_colors: &custom_colors,
+                default_collider_debug: render_context.default_collider_debug,
+                override_visibility: &override_visibility,
+                context_colliders: rapier_context_colliders,
+                bodies: &rigidbody_set.bodies,
+                narrow_phase: &rapier_context.narrow_phase,
+                collider_state_colors: render_context.collider_state_colors,
+                draw,
+            };
+
+            let unscaled_style = render_context.pipeline.style;
+            render_context.pipeline.render(
+                &mut backend,
+                &rigidbody_set.bodies,
+                &rapier_context_colliders.colliders,
+                &joints.impulse_joints,
+                &joints.multibody_joints,
+                &rapier_context.narrow_phase,
+            );
+            render_context.pipeline.style = unscaled_style;
+
+            render_context.overlays.render(
+                backend.draw,
+                &rigidbody_set.bodies,
+                &external_forces,
+                &mass_properties,
+            );
+
+            shapes.set_style(render_context.pipeline.style);
+            scene_queries.render(
+                backend.draw,
+                &mut shapes,
+                queries.iter().map(|(query, _)| query),
+            );
+            for (controller, output, collider, transform, link) in &character_controllers {
+                if link.0 == context_entity {
+                    render_context.character_controllers.render(
+                        backend.draw,
+                        &mut shapes,
+                        controller,
+                        output,
+                        collider,
+                        transform,
+                    );
+                }
+            }
         };
 
-        let unscaled_style = render_context.pipeline.style;
-        render_context.pipeline.render(
-            &mut backend,
-            &rigidbody_set.bodies,
-            &rapier_context_colliders.colliders,
-            &joints.impulse_joints,
-            &joints.multibody_joints,
-            &rapier_context.narrow_phase,
+        let Some(render_layers) = render_layers else {
+            render(&mut |a, b, color| gizmos.line(a, b, color));
+            continue;
+        };
+
+        // Render the lines into a retained gizmo, to give them the render layers of the context.
+        match context_gizmos.remove(&context_entity) {
+            Some((_, mut gizmo, mut layers)) => {
+                if let Some(asset) = gizmo_assets.get_mut(&gizmo.handle) {
+                    asset.clear();
+                    render(&mut |a, b, color| asset.line(a, b, color));
+                }
+                gizmo.line_config = line_config.clone();
+                gizmo.depth_bias = depth_bias;
+                if *layers != *render_layers {
+                    *layers = render_layers.clone();
+                }
+            }
+            None => {
+                let mut asset = GizmoAsset::new();
+                render(&mut |a, b, color| asset.line(a, b, color));
+                commands.spawn((
+                    RapierContextDebugGizmo(context_entity),
+                    Gizmo {
+                        handle: gizmo_assets.add(asset),
+                        line_config: line_config.clone(),
+                        depth_bias,
+                    },
+                    render_layers.clone(),
+                ));
+            }
+        }
+    }
+
+    // Forget the queries rendered during enough frames, and the queries of removed contexts.
+    logged_queries.retain(|context, queries| {
+        queries.retain_mut(|(_, frames)| {
+            *frames = frames.saturating_sub(1);
+            *frames > 0
+        });
+        rapier_context.contains(*context)
+    });
+
+    // Remove the gizmos of the contexts which aren't rendered with their own render layers anymore.
+    for (entity, _, _) in context_gizmos.into_values() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugin::{context::DefaultRapierContext, NoUserData, RapierPhysicsPlugin};
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+
+    fn context_gizmos(app: &mut App) -> Vec<(Entity, RenderLayers, usize)> {
+        let world = app.world_mut();
+        let gizmos: Vec<_> = world
+            .query::<(&RapierContextDebugGizmo, &Gizmo, &RenderLayers)>()
+            .iter(world)
+            .map(|(context, gizmo, layers)| (context.0, gizmo.handle.clone(), layers.clone()))
+            .collect();
+        let assets = world.resource::<Assets<GizmoAsset>>();
+        gizmos
+            .into_iter()
+            .map(|(context, handle, layers)| {
+                let lines = assets.get(&handle).unwrap().buffer().list_positions.len() / 2;
+                (context, layers, lines)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn context_render_layers_use_a_retained_gizmo() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TransformPlugin,
+            TimePlugin,
+            AssetPlugin::default(),
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default().disabled(),
+        ))
+        // The `GizmoPlugin` requires the renderer: only the retained gizmo assets are needed.
+        .init_asset::<GizmoAsset>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs_f32(1.0 / 60.0),
+        ));
+        app.finish();
+        app.update();
+
+        app.world_mut()
+            .spawn((Transform::default(), Collider::ball(0.5)));
+        let world = app.world_mut();
+        let context = world
+            .query_filtered::<Entity, With<DefaultRapierContext>>()
+            .single(world)
+            .unwrap();
+        world.entity_mut(context).insert(RenderLayers::layer(1));
+        app.update();
+        app.update();
+        assert!(
+            context_gizmos(&mut app).is_empty(),
+            "Nothing should be rendered while the debug-renderer is disabled"
+        );
+
+        // The context overrides the disabled global configuration.
+        app.world_mut()
+            .entity_mut(context)
+            .insert(DebugRenderContext::default());
+        app.update();
+        app.update();
+        let gizmos = context_gizmos(&mut app);
+        assert_eq!(gizmos.len(), 1);
+        let (gizmo_context, layers, lines) = gizmos[0].clone();
+        assert_eq!(gizmo_context, context);
+        assert_eq!(layers, RenderLayers::layer(1));
+        assert!(lines > 0, "The collider should be rendered into the gizmo");
+
+        app.world_mut()
+            .entity_mut(context)
+            .insert(RenderLayers::layer(2));
+        app.update();
+        let gizmos = context_gizmos(&mut app);
+        assert_eq!(gizmos.len(), 1, "The gizmo of the context should be reused");
+        assert_eq!(gizmos[0].1, RenderLayers::layer(2));
+
+        app.world_mut().entity_mut(context).remove::<RenderLayers>();
+        app.update();
+        assert!(
+            context_gizmos(&mut app).is_empty(),
+            "The gizmo should be despawned once the context has no render layers"
         );
-        render_context.pipeline.style = unscaled_style;
     }
 }
@@ -0,0 +1,347 @@
+use bevy::prelude::*;
+use rapier::geometry::{ColliderBuilder, ColliderHandle, ColliderSet};
+use rapier::math::{Isometry, Point, Real};
+use rapier::pipeline::{
+    DebugRenderBackend, DebugRenderMode, DebugRenderObject, DebugRenderPipeline, DebugRenderStyle,
+};
+use rapier::prelude::RigidBodySet;
+
+use super::body_state::{draw_cross, to_vec3};
+use crate::control::{KinematicCharacterController, KinematicCharacterControllerOutput};
+use crate::geometry::Collider;
+use crate::math::Vect;
+use crate::plugin::context::{LoggedHit, LoggedQuery};
+use crate::utils;
+
+#[cfg(doc)]
+use crate::plugin::context::{QueryLog, RapierQueryPipeline};
+
+/// How the debug-renderer renders the scene queries logged by the [`QueryLog`] of the rapier
+/// contexts.
+///
+/// The query log of the [`RapierQueryPipeline`] of a rapier context is enabled by the
+/// debug-renderer while `frames` isn't zero.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct SceneQueryDebugRender {
+    /// The number of frames during which each query is rendered, or zero to disable the rendering
+    /// of the scene queries.
+    pub frames: u32,
+    /// The color of the queries which hit a collider, and of their hit points.
+    pub hit_color: Hsla,
+    /// The color of the queries which didn't hit any collider.
+    pub miss_color: Hsla,
+    /// The color of the normals at the hit points.
+    pub normal_color: Hsla,
+    /// The length of the normals at the hit points. The hit points are rendered as crosses of a
+    /// quarter of this size.
+    pub normal_length: Real,
+    /// The maximum length of the rendered rays and shape casts, for the unbounded ones.
+    pub max_length: Real,
+}
+
+impl Default for SceneQueryDebugRender {
+    fn default() -> Self {
+        Self {
+            frames: 0,
+            hit_color: Hsla::hsl(120.0, 1.0, 0.4),
+            miss_color: Hsla::hsl(0.0, 1.0, 0.5),
+            normal_color: Hsla::hsl(220.0, 1.0, 0.5),
+            #[cfg(feature = "dim2")]
+            normal_length: 20.0,
+            #[cfg(feature = "dim3")]
+            normal_length: 0.5,
+            #[cfg(feature = "dim2")]
+            max_length: 10_000.0,
+            #[cfg(feature = "dim3")]
+            max_length: 100.0,
+        }
+    }
+}
+
+impl SceneQueryDebugRender {
+    /// The default configuration, rendering each scene query during the given number of frames.
+    pub fn for_frames(frames: u32) -> Self {
+        Self {
+            frames,
+            ..Default::default()
+        }
+    }
+
+    /// Renders the given logged queries as lines.
+    pub(crate) fn render<'a>(
+        &self,
+        draw: &mut dyn FnMut(Vec3, Vec3, Color),
+        shapes: &mut ShapeRenderer,
+        queries: impl Iterator<Item = &'a LoggedQuery>,
+    ) {
+        for query in queries {
+            let color = if query.is_hit() {
+                self.hit_color
+            } else {
+                self.miss_color
+            };
+
+            match query {
+                LoggedQuery::Ray {
+                    origin,
+                    dir,
+                    max_toi,
+                    hit,
+                } => {
+                    let end =
+                        hit.map_or_else(|| self.cast_end(*origin, *dir, *max_toi), |hit| hit.point);
+                    draw(to_vec3(*origin), to_vec3(end), color.into());
+                    self.render_hit(draw, hit);
+                }
+                LoggedQuery::ShapeCast {
+                    shape,
+                    position,
+                    rotation,
+                    velocity,
+                    max_toi,
+                    hit,
+                } => {
+                    let end = hit.map_or_else(
+                        || self.cast_end(*position, *velocity, *max_toi),
+                        |hit| *position + *velocity * hit.time_of_impact,
+                    );
+                    shapes.render(draw, shape, (*position, *rotation).into(), color.into());
+                    shapes.render(draw, shape, (end, *rotation).into(), color.into());
+                    draw(to_vec3(*position), to_vec3(end), color.into());
+                    self.render_hit(draw, hit);
+                }
+                LoggedQuery::ShapeIntersections {
+                    shape,
+                    position,
+                    rotation,
+                    ..
+                } => {
+                    shapes.render(draw, shape, (*position, *rotation).into(), color.into());
+                }
+                LoggedQuery::PointProjection { point, hit } => {
+                    draw_cross(
+                        draw,
+                        to_vec3(*point),
+                        self.normal_length / 4.0,
+                        color.into(),
+                    );
+                    if let Some(hit) = hit {
+                        draw(to_vec3(*point), to_vec3(hit.point), color.into());
+                    }
+                    self.render_hit(draw, hit);
+                }
+            }
+        }
+    }
+
+    /// The end of a ray or shape cast which didn't hit anything.
+    fn cast_end(&self, origin: Vect, dir: Vect, max_toi: Real) -> Vect {
+        let length = (dir.length() * max_toi).min(self.max_length);
+        origin + dir.normalize_or_zero() * length
+    }
+
+    fn render_hit(&self, draw: &mut dyn FnMut(Vec3, Vec3, Color), hit: &Option<LoggedHit>) {
+        let Some(hit) = hit else {
+            return;
+        };
+        let point = to_vec3(hit.point);
+        draw_cross(draw, point, self.normal_length / 4.0, self.hit_color.into());
+        if let Some(normal) = hit.normal {
+            draw(
+                point,
+                point + to_vec3(normal) * self.normal_length,
+                self.normal_color.into(),
+            );
+        }
+    }
+}
+
+/// How the debug-renderer renders the [`KinematicCharacterController`]s.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct CharacterControllerDebugRender {
+    /// Whether the character controllers are rendered.
+    pub enabled: bool,
+    /// The color of the shape of the grounded character controllers.
+    pub grounded_color: Hsla,
+    /// The color of the shape of the character controllers which aren't grounded.
+    pub airborne_color: Hsla,
+    /// The color of the points and normals of the collisions of the character controllers.
+    pub collision_color: Hsla,
+    /// The color of the effective translation of the character controllers during the last
+    /// simulation step. Their desired translation is rendered with a lower alpha.
+    pub translation_color: Hsla,
+    /// The length of the normals of the collisions. The collision points are rendered as crosses
+    /// of a quarter of this size.
+    pub normal_length: Real,
+}
+
+impl Default for CharacterControllerDebugRender {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            grounded_color: Hsla::hsl(120.0, 1.0, 0.4),
+            airborne_color: Hsla::hsl(40.0, 1.0, 0.5),
+            collision_color: Hsla::hsl(0.0, 1.0, 0.5),
+            translation_color: Hsla::hsl(270.0, 1.0, 0.6),
+            #[cfg(feature = "dim2")]
+            normal_length: 20.0,
+            #[cfg(feature = "dim3")]
+            normal_length: 0.5,
+        }
+    }
+}
+
+impl CharacterControllerDebugRender {
+    /// The default configuration, with the rendering of the character controllers enabled.
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Renders the given character controller as lines: its shape, colored depending on whether
+    /// it is grounded, its translation and its collisions during the last simulation step.
+    pub(crate) fn render(
+        &self,
+        draw: &mut dyn FnMut(Vec3, Vec3, Color),
+        shapes: &mut ShapeRenderer,
+        controller: &KinematicCharacterController,
+        output: Option<&KinematicCharacterControllerOutput>,
+        collider: Option<&Collider>,
+        transform: &GlobalTransform,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let position = utils::transform_to_iso(&transform.compute_transform());
+        let color = if output.is_some_and(|output| output.grounded) {
+            self.grounded_color
+        } else {
+            self.airborne_color
+        };
+        if let Some((shape, translation, rotation)) = &controller.custom_shape {
+            let mut scaled_shape = shape.clone();
+            scaled_shape.set_scale(shape.scale, 20);
+            let shape_position = position * Isometry::from((*translation, *rotation));
+            shapes.render(draw, &scaled_shape, shape_position, color.into());
+        } else if let Some(collider) = collider {
+            shapes.render(draw, collider, position, color.into());
+        }
+
+        let Some(output) = output else {
+            return;
+        };
+        let end = transform.translation();
+        let start = end - to_vec3(output.effective_translation);
+        draw(
+            start,
+            start + to_vec3(output.desired_translation),
+            self.translation_color.with_alpha(0.3).into(),
+        );
+        draw(start, end, self.translation_color.into());
+
+        for collision in &output.collisions {
+            let Some(details) = collision.hit.details else {
+                continue;
+            };
+            let point = to_vec3(details.witness1);
+            draw_cross(
+                draw,
+                point,
+                self.normal_length / 4.0,
+                self.collision_color.into(),
+            );
+            draw(
+                point,
+                point + to_vec3(details.normal1) * self.normal_length,
+                self.collision_color.into(),
+            );
+        }
+    }
+}
+
+/// A [`DebugRenderBackend`] drawing all the lines with the same color.
+struct SingleColorBackend<'a> {
+    draw: &'a mut dyn FnMut(Vec3, Vec3, Color),
+    color: Color,
+}
+
+impl DebugRenderBackend for SingleColorBackend<'_> {
+    fn draw_line(
+        &mut self,
+        _object: DebugRenderObject,
+        a: Point<Real>,
+        b: Point<Real>,
+        _color: [f32; 4],
+    ) {
+        (self.draw)(
+            to_vec3(a.coords.into()),
+            to_vec3(b.coords.into()),
+            self.color,
+        );
+    }
+}
+
+/// Draws the outlines of shapes which aren't attached to a rapier context, reusing the same
+/// collider set and debug-render pipeline for all of them.
+pub(crate) struct ShapeRenderer {
+    colliders: ColliderSet,
+    bodies: RigidBodySet,
+    handle: Option<ColliderHandle>,
+    pipeline: DebugRenderPipeline,
+}
+
+impl Default for ShapeRenderer {
+    fn default() -> Self {
+        Self {
+            colliders: ColliderSet::new(),
+            bodies: RigidBodySet::new(),
+            handle: None,
+            pipeline: DebugRenderPipeline::new(
+                DebugRenderStyle::default(),
+                DebugRenderMode::COLLIDER_SHAPES,
+            ),
+        }
+    }
+}
+
+impl ShapeRenderer {
+    /// Sets the style of the shapes rendered next.
+    pub(crate) fn set_style(&mut self, style: DebugRenderStyle) {
+        self.pipeline.style = style;
+    }
+
+    /// Draws the outline of the given shape.
+    pub(crate) fn render(
+        &mut self,
+        draw: &mut dyn FnMut(Vec3, Vec3, Color),
+        shape: &Collider,
+        position: Isometry<Real>,
+        color: Color,
+    ) {
+        match self
+            .handle
+            .and_then(|handle| self.colliders.get_mut(handle))
+        {
+            Some(collider) => {
+                collider.set_shape(shape.raw.clone());
+                collider.set_position(position);
+            }
+            None => {
+                self.handle = Some(
+                    self.colliders
+                        .insert(ColliderBuilder::new(shape.raw.clone()).position(position)),
+                );
+            }
+        }
+        self.pipeline.render_colliders(
+            &mut SingleColorBackend { draw, color },
+            &self.bodies,
+            &self.colliders,
+        );
+    }
+}